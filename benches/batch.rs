@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use particle_id::batch::is_lepton_mask;
+use particle_id::ParticleID;
+
+fn ids(n: usize) -> Vec<i32> {
+    const CODES: [i32; 8] = [11, -11, 13, 22, 211, -211, 2212, 21];
+    (0..n)
+        .map(|i| CODES[(7 * i + i / 3) % CODES.len()])
+        .collect()
+}
+
+fn is_lepton(c: &mut Criterion) {
+    let ids = ids(1 << 20);
+    c.bench_function("is_lepton scalar", |b| {
+        b.iter(|| {
+            black_box(&ids)
+                .iter()
+                .filter(|&&id| ParticleID::new(id).is_lepton())
+                .count()
+        })
+    });
+    c.bench_function("is_lepton mask", |b| {
+        b.iter(|| is_lepton_mask(black_box(&ids)))
+    });
+}
+
+criterion_group!(benches, is_lepton);
+criterion_main!(benches);
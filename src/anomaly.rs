@@ -0,0 +1,156 @@
+//! Gauge anomalies of chiral fermion content
+//!
+//! The fermion content is given as a list of particles together with
+//! their chirality. Each entry stands for a single member of an SU(2)
+//! multiplet, including all colors. Hypercharges follow the
+//! convention Y = Q - T₃.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::anomaly::*;
+//! use particle_id::sm_elementary_particles::*;
+//! use Chirality::*;
+//!
+//! let generation = [
+//!     (u, Left), (d, Left), (u, Right), (d, Right),
+//!     (nu_e, Left), (electron, Left), (electron, Right),
+//! ];
+//! let anomalies = anomaly_coefficients(&generation).unwrap();
+//! assert!(anomalies.is_anomaly_free());
+//!
+//! let anomalies = anomaly_coefficients(&generation[..6]).unwrap();
+//! assert!(!anomalies.is_anomaly_free());
+//! ```
+use crate::ParticleID;
+
+/// Chirality of a fermion field
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Chirality {
+    /// Left-handed
+    Left,
+    /// Right-handed
+    Right,
+}
+
+/// Anomaly coefficients of a set of chiral fermions
+///
+/// Right-handed fermions contribute with the opposite sign of
+/// left-handed ones. Hypercharges enter in units of 1/6, so that all
+/// coefficients are integers.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct AnomalyCoefficients {
+    /// SU(3)³ anomaly
+    pub su3_cubed: i64,
+    /// SU(3)² U(1)_Y anomaly, in units of 1/6
+    pub su3_squared_u1: i64,
+    /// SU(2)² U(1)_Y anomaly, in units of 1/6
+    pub su2_squared_u1: i64,
+    /// U(1)_Y³ anomaly, in units of 1/216
+    pub u1_cubed: i64,
+    /// Mixed gravitational U(1)_Y anomaly, in units of 1/6
+    pub gravitational_u1: i64,
+    /// Number of SU(2) doublet members, counting colors separately
+    ///
+    /// The global SU(2) anomaly cancels if the number of doublets,
+    /// i.e. half this number, is even.
+    pub num_doublet_members: u64,
+}
+
+impl AnomalyCoefficients {
+    /// Check if all anomalies cancel
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn is_anomaly_free(&self) -> bool {
+        self.su3_cubed == 0
+            && self.su3_squared_u1 == 0
+            && self.su2_squared_u1 == 0
+            && self.u1_cubed == 0
+            && self.gravitational_u1 == 0
+            && self.num_doublet_members % 4 == 0
+    }
+}
+
+/// Third component of the weak isospin of a chiral fermion times two
+///
+/// Left-handed fermions and right-handed anti-fermions form SU(2)
+/// doublets, whereas the other components are singlets. Returns
+/// `None` for particles other than elementary fermions.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::anomaly::*;
+/// use particle_id::sm_elementary_particles::*;
+/// assert_eq!(t3_times_two(electron, Chirality::Left), Some(-1));
+/// assert_eq!(t3_times_two(electron, Chirality::Right), Some(0));
+/// assert_eq!(t3_times_two(positron, Chirality::Right), Some(1));
+/// ```
+pub const fn t3_times_two(
+    particle: ParticleID,
+    chirality: Chirality,
+) -> Option<i32> {
+    let Some(t3) = particle.t3_times_two() else {
+        return None;
+    };
+    let is_doublet = matches!(
+        (particle.is_anti_particle(), chirality),
+        (false, Chirality::Left) | (true, Chirality::Right)
+    );
+    Some(if is_doublet { t3 } else { 0 })
+}
+
+/// Hypercharge Y = Q - T₃ of a chiral fermion times six
+///
+/// Returns `None` for particles other than elementary fermions.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::anomaly::*;
+/// use particle_id::sm_elementary_particles::*;
+/// assert_eq!(hypercharge_times_six(u, Chirality::Left), Some(1));
+/// assert_eq!(hypercharge_times_six(u, Chirality::Right), Some(4));
+/// assert_eq!(hypercharge_times_six(electron, Chirality::Right), Some(-6));
+/// ```
+pub const fn hypercharge_times_six(
+    particle: ParticleID,
+    chirality: Chirality,
+) -> Option<i32> {
+    match (
+        particle.charge_in_thirds(),
+        t3_times_two(particle, chirality),
+    ) {
+        (Some(charge), Some(t3)) => Some(2 * charge - 3 * t3),
+        _ => None,
+    }
+}
+
+/// Compute the anomaly coefficients of the given chiral fermions
+///
+/// Returns `None` if any of the particles is not an elementary
+/// fermion.
+pub fn anomaly_coefficients(
+    fermions: &[(ParticleID, Chirality)],
+) -> Option<AnomalyCoefficients> {
+    let mut anomalies = AnomalyCoefficients::default();
+    for &(particle, chirality) in fermions {
+        let hypercharge = hypercharge_times_six(particle, chirality)? as i64;
+        let sign = match chirality {
+            Chirality::Left => 1,
+            Chirality::Right => -1,
+        };
+        let colors = particle.color_multiplicity() as i64;
+        if colors == 3 {
+            let triality = if particle.is_anti_particle() { -1 } else { 1 };
+            anomalies.su3_cubed += sign * triality;
+            anomalies.su3_squared_u1 += sign * hypercharge;
+        }
+        if t3_times_two(particle, chirality)? != 0 {
+            anomalies.su2_squared_u1 += sign * colors * hypercharge;
+            anomalies.num_doublet_members += colors as u64;
+        }
+        anomalies.u1_cubed += sign * colors * hypercharge.pow(3);
+        anomalies.gravitational_u1 += sign * colors * hypercharge;
+    }
+    Some(anomalies)
+}
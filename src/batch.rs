@@ -0,0 +1,133 @@
+//! Predicates evaluated on whole slices of particle codes
+//!
+//! The functions in this module are equivalent to calling the
+//! corresponding [ParticleID] method on each element, but are written
+//! such that the compiler can auto-vectorize them. This is useful for
+//! classifying large numbers of particles, for example in trigger-like
+//! workloads.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::batch::is_lepton_mask;
+//!
+//! let mask = is_lepton_mask(&[11, -11, 22, 13]);
+//! assert_eq!(mask.len(), 4);
+//! assert_eq!(mask.iter().collect::<Vec<_>>(), [true, false, false, true]);
+//! assert_eq!(mask.count_ones(), 2);
+//!
+//! let ids: Vec<i32> = (-100..100).collect();
+//! let mask = is_lepton_mask(&ids);
+//! for (id, is_lepton) in ids.into_iter().zip(mask.iter()) {
+//!     assert_eq!(is_lepton, (11..=18).contains(&id));
+//! }
+//! ```
+use crate::ParticleID;
+
+const BITS: usize = u64::BITS as usize;
+
+/// Packed sequence of booleans, one bit per particle
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitMask {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitMask {
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the mask is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Value of the entry at position `idx`
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        if idx < self.len {
+            Some(self.words[idx / BITS] & (1 << (idx % BITS)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Number of entries that are `true`
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Iterate over all entries
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len)
+            .map(|idx| self.words[idx / BITS] & (1 << (idx % BITS)) != 0)
+    }
+
+    /// The underlying words
+    ///
+    /// Entry `i` is stored in bit `i % 64` of word `i / 64`. Unused
+    /// bits in the last word are zero.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+}
+
+// Pack a chunk of at most 64 predicate values into the bits of a word
+//
+// The predicate is first evaluated into a byte array, which
+// vectorizes well, and the bytes are then packed eight at a time.
+#[inline(always)]
+fn pack(chunk: &[i32], pred: &impl Fn(ParticleID) -> bool) -> u64 {
+    let mut flags = [0u8; BITS];
+    for (flag, &id) in flags.iter_mut().zip(chunk) {
+        *flag = pred(ParticleID(id)) as u8;
+    }
+    let mut word = 0;
+    for (i, bytes) in flags.chunks_exact(8).enumerate() {
+        let bytes = u64::from_le_bytes(bytes.try_into().unwrap());
+        word |= (bytes.wrapping_mul(0x0102_0408_1020_4080) >> 56) << (8 * i);
+    }
+    word
+}
+
+#[inline(always)]
+fn mask_by(ids: &[i32], pred: impl Fn(ParticleID) -> bool) -> BitMask {
+    let words = ids.chunks(BITS).map(|chunk| pack(chunk, &pred)).collect();
+    BitMask {
+        words,
+        len: ids.len(),
+    }
+}
+
+/// Mask of codes for which [is_quark](ParticleID::is_quark) is true
+pub fn is_quark_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_quark())
+}
+
+/// Mask of codes for which [is_lepton](ParticleID::is_lepton) is true
+pub fn is_lepton_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_lepton())
+}
+
+/// Mask of codes for which [is_charged_lepton](ParticleID::is_charged_lepton) is true
+pub fn is_charged_lepton_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_charged_lepton())
+}
+
+/// Mask of codes for which [is_neutrino](ParticleID::is_neutrino) is true
+pub fn is_neutrino_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_neutrino())
+}
+
+/// Mask of codes for which [is_gauge_boson](ParticleID::is_gauge_boson) is true
+pub fn is_gauge_boson_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_gauge_boson())
+}
+
+/// Mask of codes for which [is_anti_particle](ParticleID::is_anti_particle) is true
+pub fn is_anti_particle_mask(ids: &[i32]) -> BitMask {
+    mask_by(ids, |p| p.is_anti_particle())
+}
@@ -0,0 +1,84 @@
+use crate::ParticleID;
+
+/// Typical beam species
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum BeamKind {
+    /// Proton
+    Proton,
+    /// Anti-proton
+    AntiProton,
+    /// Electron
+    Electron,
+    /// Positron
+    Positron,
+    /// Negative muon
+    Muon,
+    /// Positive muon
+    AntiMuon,
+    /// Photon
+    Photon,
+    /// Nucleus with at least one proton and more than one nucleon
+    Ion,
+}
+
+impl ParticleID {
+    /// Kind of beam this particle is typically used for
+    ///
+    /// Returns `None` for particles that are not typical beam
+    /// species.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::{BeamKind, ParticleID};
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(proton.beam_kind(), Some(BeamKind::Proton));
+    /// assert_eq!(proton.anti().beam_kind(), Some(BeamKind::AntiProton));
+    /// // lead-208
+    /// let pb = ParticleID::new(1000822080);
+    /// assert_eq!(pb.beam_kind(), Some(BeamKind::Ion));
+    /// assert_eq!(neutron.beam_kind(), None);
+    /// // a neutron in the nucleus format
+    /// assert_eq!(ParticleID::new(1000000010).beam_kind(), None);
+    /// # }
+    /// ```
+    pub const fn beam_kind(&self) -> Option<BeamKind> {
+        let kind = match self.0 {
+            2212 | 1000010010 => BeamKind::Proton,
+            -2212 | -1000010010 => BeamKind::AntiProton,
+            11 => BeamKind::Electron,
+            -11 => BeamKind::Positron,
+            13 => BeamKind::Muon,
+            -13 => BeamKind::AntiMuon,
+            22 => BeamKind::Photon,
+            _ if self.has_nucleus_digits() && !self.is_anti_particle() => {
+                let z = (self.0 / 10_000) % 1000;
+                let a = (self.0 / 10) % 1000;
+                if z < 1 || a < 2 {
+                    return None;
+                }
+                BeamKind::Ion
+            }
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    /// Check if this is a typical beam particle
+    ///
+    /// See [beam_kind](Self::beam_kind).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(positron.is_beam_particle());
+    /// assert!(photon.is_beam_particle());
+    /// assert!(!tau.is_beam_particle());
+    /// ```
+    pub const fn is_beam_particle(&self) -> bool {
+        self.beam_kind().is_some()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::ParticleID;
+
+/// Detector response category of a final-state particle
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CaloCategory {
+    /// Electromagnetic shower from electrons, positrons, and photons
+    Electromagnetic,
+    /// Hadronic shower from a charged hadron
+    ChargedHadron,
+    /// Hadronic shower from a neutral hadron
+    NeutralHadron,
+    /// Minimum ionizing muon
+    Muon,
+    /// No interaction with the detector
+    Invisible,
+}
+
+impl ParticleID {
+    /// Detector response category, as used in parameterized fast simulations
+    ///
+    /// Returns `None` for particles that do not reach the
+    /// calorimeter, like partons, tau leptons, and promptly decaying
+    /// resonances. Weakly decaying hadrons are treated as stable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::CaloCategory;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// assert_eq!(photon.calo_category(), Some(CaloCategory::Electromagnetic));
+    /// assert_eq!(anti_muon.calo_category(), Some(CaloCategory::Muon));
+    /// assert_eq!(nu_e.calo_category(), Some(CaloCategory::Invisible));
+    /// assert_eq!(
+    ///     π_plus.calo_category(),
+    ///     Some(CaloCategory::ChargedHadron)
+    /// );
+    /// assert_eq!(
+    ///     K_0_L.calo_category(),
+    ///     Some(CaloCategory::NeutralHadron)
+    /// );
+    /// assert_eq!(π_0.calo_category(), None);
+    /// assert_eq!(gluon.calo_category(), None);
+    /// # }
+    /// ```
+    pub const fn calo_category(&self) -> Option<CaloCategory> {
+        match self.0.unsigned_abs() {
+            11 | 22 => return Some(CaloCategory::Electromagnetic),
+            13 => return Some(CaloCategory::Muon),
+            // neutrinos, graviton, dark matter, lightest neutralino,
+            // gravitino
+            12 | 14 | 16 | 18 | 39 | 51..=53 | 1000022 | 1000039 => {
+                return Some(CaloCategory::Invisible)
+            }
+            _ => {}
+        }
+        let is_hadron = self.has_meson_digits()
+            || self.has_baryon_digits()
+            || self.has_nucleus_digits();
+        if !is_hadron || self.is_promptly_decaying() {
+            return None;
+        }
+        match self.charge_in_thirds() {
+            Some(0) => Some(CaloCategory::NeutralHadron),
+            Some(_) => Some(CaloCategory::ChargedHadron),
+            None => None,
+        }
+    }
+}
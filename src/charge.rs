@@ -0,0 +1,120 @@
+use std::fmt::{self, Display};
+
+use crate::ParticleID;
+
+/// Electric charge
+///
+/// The charge is stored exactly, as an integer multiple of e/3.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::Charge;
+/// let charge = Charge::from_thirds(-1);
+/// assert_eq!(charge.in_thirds(), -1);
+/// assert!(!charge.is_integer());
+/// assert_eq!(charge.to_string(), "-1/3");
+/// assert_eq!(Charge::from_thirds(6).to_string(), "2");
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Charge(i32);
+
+impl Charge {
+    /// Charge of the given number of units of e/3
+    pub const fn from_thirds(thirds: i32) -> Self {
+        Self(thirds)
+    }
+
+    /// Charge in units of e/3
+    pub const fn in_thirds(&self) -> i32 {
+        self.0
+    }
+
+    /// Charge in units of e
+    pub const fn as_f64(&self) -> f64 {
+        self.0 as f64 / 3.
+    }
+
+    /// Check if the charge is an integer multiple of e
+    pub const fn is_integer(&self) -> bool {
+        self.0 % 3 == 0
+    }
+
+    /// Check if the charge vanishes
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Display for Charge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.0 / 3)
+        } else {
+            write!(f, "{}/3", self.0)
+        }
+    }
+}
+
+impl ParticleID {
+    /// Electric charge
+    ///
+    /// See [charge_in_thirds](Self::charge_in_thirds).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(up.charge().unwrap().as_f64(), 2. / 3.);
+    /// assert!(photon.charge().unwrap().is_zero());
+    /// ```
+    pub const fn charge(&self) -> Option<Charge> {
+        match self.charge_in_thirds() {
+            Some(thirds) => Some(Charge(thirds)),
+            None => None,
+        }
+    }
+
+    /// Check if this particle has a non-zero electric charge
+    ///
+    /// Returns `false` if the charge is not known, so a particle can be
+    /// neither charged nor neutral.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(positron.is_charged());
+    /// assert!(π_plus.anti().is_charged());
+    /// assert!(d.is_charged());
+    /// assert!(!neutron.is_charged());
+    /// # }
+    /// ```
+    pub const fn is_charged(&self) -> bool {
+        matches!(self.charge_in_thirds(), Some(thirds) if thirds != 0)
+    }
+
+    /// Check if this particle is electrically neutral
+    ///
+    /// See [is_charged](Self::is_charged).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// assert!(photon.is_neutral());
+    /// assert!(π_0.is_neutral());
+    /// assert!(!electron.is_neutral());
+    /// assert!(!ParticleID::new(0).is_neutral());
+    /// # }
+    /// ```
+    pub const fn is_neutral(&self) -> bool {
+        matches!(self.charge_in_thirds(), Some(0))
+    }
+}
@@ -0,0 +1,114 @@
+//! Elements of the Cabibbo-Kobayashi-Maskawa (CKM) matrix
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::ckm::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! // W⁺ → c s̄
+//! let v_cs = ckm_element(c, s_bar).unwrap();
+//! assert_eq!(v_cs.indices(), (1, 1));
+//! assert_eq!(v_cs.to_string(), "V_cs");
+//! assert_eq!(ckm_element(s, c), None);
+//! ```
+use std::fmt::{self, Display};
+
+#[cfg(feature = "masses")]
+use crate::Measurement;
+use crate::ParticleID;
+
+/// Element of the CKM matrix
+///
+/// Rows correspond to the up-type quarks u, c, t and columns to the
+/// down-type quarks d, s, b.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct CkmElement {
+    row: u8,
+    column: u8,
+}
+
+impl CkmElement {
+    /// Zero-based row and column indices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ckm::ckm_element;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(ckm_element(u, b).unwrap().indices(), (0, 2));
+    /// ```
+    pub const fn indices(&self) -> (usize, usize) {
+        (self.row as usize, self.column as usize)
+    }
+
+    /// Up-type quark labelling the row
+    pub const fn up_type_quark(&self) -> ParticleID {
+        ParticleID(2 * self.row as i32 + 2)
+    }
+
+    /// Down-type quark labelling the column
+    pub const fn down_type_quark(&self) -> ParticleID {
+        ParticleID(2 * self.column as i32 + 1)
+    }
+
+    /// Magnitude of the matrix element with its uncertainty
+    ///
+    /// Values are taken from the [Review of Particle
+    /// Physics](https://pdg.lbl.gov) and are determined from direct
+    /// measurements without imposing unitarity. Requires the `masses`
+    /// feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ckm::ckm_element;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let v_ud = ckm_element(u, d).unwrap().magnitude();
+    /// assert_eq!(v_ud.value, 0.97367);
+    /// assert_eq!(v_ud.uncertainty, 0.00032);
+    /// ```
+    #[cfg(feature = "masses")]
+    pub const fn magnitude(&self) -> Measurement {
+        let (value, uncertainty) = match (self.row, self.column) {
+            (0, 0) => (0.97367, 0.00032),
+            (0, 1) => (0.22431, 0.00085),
+            (0, 2) => (3.82e-3, 0.20e-3),
+            (1, 0) => (0.221, 0.004),
+            (1, 1) => (0.975, 0.006),
+            (1, 2) => (41.1e-3, 1.2e-3),
+            (2, 0) => (8.6e-3, 0.2e-3),
+            (2, 1) => (41.5e-3, 0.9e-3),
+            _ => (1.010, 0.027),
+        };
+        Measurement { value, uncertainty }
+    }
+}
+
+impl Display for CkmElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UP: [char; 3] = ['u', 'c', 't'];
+        const DOWN: [char; 3] = ['d', 's', 'b'];
+        let (row, column) = self.indices();
+        write!(f, "V_{}{}", UP[row], DOWN[column])
+    }
+}
+
+/// CKM matrix element connecting an up-type and a down-type quark
+///
+/// Quarks and anti-quarks are treated alike, so that for example
+/// both u → d W⁺ and W⁺ → u d̄ correspond to V_ud. Returns `None`
+/// unless `up_type` is one of u, c, t and `down_type` one of d, s, b,
+/// or their anti-quarks.
+pub const fn ckm_element(
+    up_type: ParticleID,
+    down_type: ParticleID,
+) -> Option<CkmElement> {
+    match (up_type.0.unsigned_abs(), down_type.0.unsigned_abs()) {
+        (up @ (2 | 4 | 6), down @ (1 | 3 | 5)) => Some(CkmElement {
+            row: (up / 2 - 1) as u8,
+            column: (down / 2) as u8,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,150 @@
+use crate::index::{from_slot, NUM_KNOWN};
+use crate::ParticleID;
+
+/// Classification of particles
+///
+/// Anti-particles belong to the same class as the corresponding
+/// particles.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParticleClass {
+    /// Quarks
+    Quark,
+    /// Charged leptons and neutrinos
+    Lepton,
+    /// Charged leptons
+    ChargedLepton,
+    /// Neutrinos
+    Neutrino,
+    /// Gauge bosons
+    GaugeBoson,
+    /// Higgs bosons
+    HiggsBoson,
+    /// Diquarks
+    Diquark,
+    /// Mesons
+    Meson,
+    /// Mesons made of u, d, and s quarks only
+    LightMeson,
+    /// Mesons with open charm and no bottom quark
+    CharmMeson,
+    /// Mesons with open bottom, including B_c mesons
+    BottomMeson,
+    /// Charmonium states
+    Charmonium,
+    /// Bottomonium states
+    Bottomonium,
+    /// Baryons
+    Baryon,
+    /// Baryons with at least one charm quark and no bottom quark
+    CharmBaryon,
+    /// Baryons with at least one bottom quark
+    BottomBaryon,
+    /// Pentaquarks
+    Pentaquark,
+    /// Superpartners
+    Susy,
+}
+
+impl ParticleClass {
+    /// Check if the given particle belongs to this class
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::ParticleClass;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::charmed_mesons::*;
+    /// use particle_id::bottom_mesons::*;
+    /// assert!(ParticleClass::ChargedLepton.contains(positron));
+    /// assert!(ParticleClass::CharmMeson.contains(D_0.anti()));
+    /// assert!(!ParticleClass::CharmMeson.contains(B_c_plus));
+    /// assert!(ParticleClass::BottomMeson.contains(B_c_plus));
+    /// assert!(!ParticleClass::Lepton.contains(photon));
+    /// # }
+    /// ```
+    pub const fn contains(&self, particle: ParticleID) -> bool {
+        let abs = particle.abs();
+        let heavy = if particle.has_meson_digits() {
+            particle.n_q2()
+        } else {
+            particle.n_q1()
+        };
+        match self {
+            ParticleClass::Quark => abs.is_quark(),
+            ParticleClass::Lepton => abs.is_lepton(),
+            ParticleClass::ChargedLepton => abs.is_charged_lepton(),
+            ParticleClass::Neutrino => abs.is_neutrino(),
+            ParticleClass::GaugeBoson => abs.is_gauge_boson(),
+            ParticleClass::HiggsBoson => {
+                matches!(abs.0, 25 | 35 | 36 | 37 | 38 | 40)
+            }
+            ParticleClass::Diquark => particle.has_diquark_digits(),
+            ParticleClass::Meson => particle.has_meson_digits(),
+            ParticleClass::LightMeson => {
+                particle.has_meson_digits() && heavy <= 3
+            }
+            ParticleClass::CharmMeson => {
+                particle.has_meson_digits()
+                    && heavy == 4
+                    && particle.n_q3() != 4
+            }
+            ParticleClass::BottomMeson => {
+                particle.has_meson_digits()
+                    && heavy == 5
+                    && particle.n_q3() != 5
+            }
+            ParticleClass::Charmonium => {
+                particle.has_meson_digits()
+                    && heavy == 4
+                    && particle.n_q3() == 4
+            }
+            ParticleClass::Bottomonium => {
+                particle.has_meson_digits()
+                    && heavy == 5
+                    && particle.n_q3() == 5
+            }
+            ParticleClass::Baryon => particle.has_baryon_digits(),
+            ParticleClass::CharmBaryon => {
+                particle.has_baryon_digits() && heavy == 4
+            }
+            ParticleClass::BottomBaryon => {
+                particle.has_baryon_digits() && heavy == 5
+            }
+            ParticleClass::Pentaquark => particle.is_pentaquark(),
+            ParticleClass::Susy => particle.is_susy(),
+        }
+    }
+}
+
+impl ParticleID {
+    /// Iterate over all known particles in the given class
+    ///
+    /// Anti-particles are included. The iteration order is by the
+    /// absolute value of the code, with each particle immediately
+    /// before its anti-particle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::{ParticleClass, ParticleID};
+    /// use particle_id::sm_elementary_particles::*;
+    /// let charged_leptons: Vec<_> =
+    ///     ParticleID::iter_class(ParticleClass::ChargedLepton).collect();
+    /// assert_eq!(charged_leptons[..4], [electron, positron, muon, anti_muon]);
+    /// assert!(
+    ///     ParticleID::iter_class(ParticleClass::GaugeBoson).all(|p| p != photon.anti())
+    /// );
+    /// ```
+    pub fn iter_class(
+        class: ParticleClass,
+    ) -> impl Iterator<Item = ParticleID> {
+        (0..2 * NUM_KNOWN)
+            .map(from_slot)
+            .filter(|p| {
+                !p.is_anti_particle() || !p.abs().is_own_anti_particle()
+            })
+            .filter(move |p| class.contains(*p))
+    }
+}
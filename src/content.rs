@@ -0,0 +1,92 @@
+use crate::ParticleID;
+
+/// Constituent quarks and anti-quarks of a composite particle
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+/// use particle_id::QuarkContent;
+/// use particle_id::sm_elementary_particles::*;
+/// use particle_id::light_mesons::*;
+/// use particle_id::light_baryons::*;
+///
+/// let content = π_plus.anti().quark_content().unwrap();
+/// assert_eq!(content, QuarkContent::Meson([d, u_bar]));
+/// let content = proton.anti().quark_content().unwrap();
+/// assert_eq!(content.as_slice(), [u_bar, u_bar, d_bar]);
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum QuarkContent {
+    /// Quark followed by the anti-quark of a meson
+    Meson([ParticleID; 2]),
+    /// Quarks of a baryon, or anti-quarks of an anti-baryon
+    Baryon([ParticleID; 3]),
+    /// Quarks of a diquark, or anti-quarks of an anti-diquark
+    Diquark([ParticleID; 2]),
+    /// Quarks followed by anti-quarks of a tetraquark
+    Tetraquark([ParticleID; 4]),
+    /// Quarks followed by the anti-quark of a pentaquark
+    Pentaquark([ParticleID; 5]),
+}
+
+impl QuarkContent {
+    /// All constituents
+    pub fn as_slice(&self) -> &[ParticleID] {
+        match self {
+            QuarkContent::Meson(q) | QuarkContent::Diquark(q) => q,
+            QuarkContent::Baryon(q) => q,
+            QuarkContent::Tetraquark(q) => q,
+            QuarkContent::Pentaquark(q) => q,
+        }
+    }
+}
+
+impl ParticleID {
+    /// Constituent quarks and anti-quarks
+    ///
+    /// The constituents are decoded from the quark digits of the
+    /// particle code. For flavored mesons with positive code, the
+    /// heavier constituent is a quark if it is up-type and an
+    /// anti-quark if it is down-type. Returns `None` for particles
+    /// that are not composite, for nuclei, and for the mixtures K⁰_L
+    /// and K⁰_S.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::QuarkContent;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::mesons::*;
+    /// assert_eq!(B_plus.quark_content(), Some(QuarkContent::Meson([u, b_bar])));
+    /// assert_eq!(D_0.quark_content(), Some(QuarkContent::Meson([c, u_bar])));
+    /// assert_eq!(K_0_L.quark_content(), None);
+    /// assert_eq!(electron.quark_content(), None);
+    /// # }
+    /// ```
+    pub const fn quark_content(&self) -> Option<QuarkContent> {
+        if let Some([q1, q2]) = self.meson_quarks() {
+            if q1.is_anti_particle() {
+                Some(QuarkContent::Meson([q2, q1]))
+            } else {
+                Some(QuarkContent::Meson([q1, q2]))
+            }
+        } else if let Some(q) = self.baryon_quarks() {
+            Some(QuarkContent::Baryon(q))
+        } else if self.has_diquark_digits() {
+            Some(QuarkContent::Diquark([
+                self.constituent_quark(self.n_q1(), false),
+                self.constituent_quark(self.n_q2(), false),
+            ]))
+        } else if let Some(q) = self.tetraquark_content() {
+            Some(QuarkContent::Tetraquark(q))
+        } else if let Some(q) = self.pentaquark_content() {
+            Some(QuarkContent::Pentaquark(q))
+        } else {
+            None
+        }
+    }
+}
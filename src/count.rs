@@ -0,0 +1,120 @@
+//! Multiplicities of particle classes
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::count::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! let event = [electron, positron, photon, u, u_bar, g];
+//! assert_eq!(count_charged_leptons(event), 2);
+//! assert_eq!(count_by(event, |p| p.abs().is_quark()), 2);
+//!
+//! let counts = ClassCounts::from_iter(event);
+//! assert_eq!(counts.charged_leptons, 2);
+//! assert_eq!(counts.gauge_bosons, 2);
+//! assert_eq!(counts.total(), event.len());
+//! ```
+use crate::ParticleID;
+
+/// Count the particles for which `selector` returns `true`
+pub fn count_by<I, F>(particles: I, mut selector: F) -> usize
+where
+    I: IntoIterator<Item = ParticleID>,
+    F: FnMut(ParticleID) -> bool,
+{
+    particles.into_iter().filter(|p| selector(*p)).count()
+}
+
+/// Count quarks and anti-quarks
+pub fn count_quarks<I>(particles: I) -> usize
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    count_by(particles, |p| p.abs().is_quark())
+}
+
+/// Count charged leptons and anti-leptons
+pub fn count_charged_leptons<I>(particles: I) -> usize
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    count_by(particles, |p| p.abs().is_charged_lepton())
+}
+
+/// Count neutrinos and anti-neutrinos
+pub fn count_neutrinos<I>(particles: I) -> usize
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    count_by(particles, |p| p.abs().is_neutrino())
+}
+
+/// Count gauge bosons
+pub fn count_gauge_bosons<I>(particles: I) -> usize
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    count_by(particles, |p| p.is_gauge_boson())
+}
+
+/// Number of particles in each class
+///
+/// Particles and anti-particles are counted together.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub struct ClassCounts {
+    /// Number of quarks
+    pub quarks: usize,
+    /// Number of charged leptons
+    pub charged_leptons: usize,
+    /// Number of neutrinos
+    pub neutrinos: usize,
+    /// Number of gauge bosons
+    pub gauge_bosons: usize,
+    /// Number of particles not in any of the other classes
+    pub other: usize,
+}
+
+impl ClassCounts {
+    /// Add a single particle to the counts
+    pub fn add(&mut self, particle: ParticleID) {
+        let abs = particle.abs();
+        if abs.is_quark() {
+            self.quarks += 1;
+        } else if abs.is_charged_lepton() {
+            self.charged_leptons += 1;
+        } else if abs.is_neutrino() {
+            self.neutrinos += 1;
+        } else if abs.is_gauge_boson() {
+            self.gauge_bosons += 1;
+        } else {
+            self.other += 1;
+        }
+    }
+
+    /// Total number of particles
+    pub fn total(&self) -> usize {
+        self.quarks
+            + self.charged_leptons
+            + self.neutrinos
+            + self.gauge_bosons
+            + self.other
+    }
+}
+
+impl FromIterator<ParticleID> for ClassCounts {
+    fn from_iter<I: IntoIterator<Item = ParticleID>>(iter: I) -> Self {
+        let mut counts = Self::default();
+        counts.extend(iter);
+        counts
+    }
+}
+
+impl Extend<ParticleID> for ClassCounts {
+    fn extend<I: IntoIterator<Item = ParticleID>>(&mut self, iter: I) {
+        for particle in iter {
+            self.add(particle);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::{self, Display};
+
+use crate::{ParticleID, ParticleMap};
+
+/// Counter for the number of particles of each species
+///
+/// Iteration is in canonical order, that is sorted by the absolute
+/// value of the particle code, with each particle immediately before
+/// its anti-particle.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::ParticleCounter;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let mut counter = ParticleCounter::new();
+/// counter.incr(photon);
+/// counter.incr(positron);
+/// counter.incr(electron);
+/// counter.incr(photon);
+/// assert_eq!(counter.get(photon), 2);
+/// assert_eq!(counter.total(), 4);
+/// assert_eq!(
+///     counter.iter().collect::<Vec<_>>(),
+///     [(electron, 1), (positron, 1), (photon, 2)]
+/// );
+/// assert_eq!(counter.to_string(), "electron: 1\npositron: 1\nphoton: 2\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParticleCounter {
+    counts: ParticleMap<u64>,
+}
+
+impl ParticleCounter {
+    /// Create a counter with all counts set to zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increment the count for the given particle by one
+    pub fn incr(&mut self, particle: ParticleID) {
+        self.add(particle, 1)
+    }
+
+    /// Increment the count for the given particle by `n`
+    pub fn add(&mut self, particle: ParticleID, n: u64) {
+        if let Some(count) = self.counts.get_mut(particle) {
+            *count += n;
+        } else {
+            self.counts.insert(particle, n);
+        }
+    }
+
+    /// Count for the given particle
+    pub fn get(&self, particle: ParticleID) -> u64 {
+        self.counts.get(particle).copied().unwrap_or_default()
+    }
+
+    /// Add all counts from `other`
+    pub fn merge(&mut self, other: &ParticleCounter) {
+        for (particle, n) in other.counts.iter() {
+            self.add(particle, *n);
+        }
+    }
+
+    /// Total count over all particles
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Iterate over particles and their counts in canonical order
+    pub fn iter(&self) -> impl Iterator<Item = (ParticleID, u64)> {
+        let mut counts: Vec<_> =
+            self.counts.iter().map(|(p, n)| (p, *n)).collect();
+        counts.sort_unstable_by(|(p, _), (q, _)| p.cmp_by_abs(q));
+        counts.into_iter()
+    }
+}
+
+impl FromIterator<ParticleID> for ParticleCounter {
+    fn from_iter<I: IntoIterator<Item = ParticleID>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl Extend<ParticleID> for ParticleCounter {
+    fn extend<I: IntoIterator<Item = ParticleID>>(&mut self, iter: I) {
+        for particle in iter {
+            self.incr(particle);
+        }
+    }
+}
+
+impl Display for ParticleCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (particle, n) in self.iter() {
+            match particle.name() {
+                Some(name) => writeln!(f, "{name}: {n}")?,
+                None => writeln!(f, "{}: {n}", particle.id())?,
+            }
+        }
+        Ok(())
+    }
+}
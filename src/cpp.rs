@@ -0,0 +1,198 @@
+//! Generation of C++ headers
+//!
+//! The generated header defines the particle constants of this crate
+//! as `constexpr int` values, with one namespace for each module. It
+//! also defines a `constexpr` function `particle_id::name` that
+//! returns the [name](ParticleID::name) corresponding to a particle
+//! code. Anti-particles without a dedicated constant are obtained by
+//! negating the code of the particle. Modules that re-export other
+//! modules, like `sm_elementary_particles`, become namespaces with the
+//! corresponding `using` directives.
+//!
+//! Some constant names contain Greek letters, which requires a
+//! compiler supporting Unicode identifiers.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::cpp::cpp_header;
+//!
+//! let header = cpp_header();
+//! assert!(header.contains("namespace quarks {"));
+//! assert!(header.contains("constexpr int t = 6;"));
+//! assert!(header.contains("constexpr int top = 6;"));
+//! assert!(header.contains("constexpr int W_minus = -24;"));
+//! assert!(header.contains("using namespace anti_quarks;"));
+//! assert!(header.contains(r#"case 2212: return "proton";"#));
+//! ```
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use crate::index::{from_slot, NUM_KNOWN};
+use crate::ParticleID;
+
+macro_rules! modules {
+    ($($(#[$attr:meta])* $module:ident: [$($constants:ident),+],)*) => {
+        const MODULES: &[(&str, &[&[(&str, ParticleID)]])] = &[
+            $(
+                $(#[$attr])*
+                (
+                    stringify!($module),
+                    &[$(&crate::$module::$constants),+],
+                ),
+            )*
+        ];
+    };
+}
+
+modules!(
+    quarks: [CONSTANTS, EXTRA_CONSTANTS],
+    leptons: [CONSTANTS, EXTRA_CONSTANTS],
+    gauge_bosons: [CONSTANTS, EXTRA_CONSTANTS],
+    higgs_bosons: [CONSTANTS, EXTRA_CONSTANTS],
+    special_particles: [CONSTANTS, EXTRA_CONSTANTS],
+    diquarks: [CONSTANTS],
+    #[cfg(feature = "susy")]
+    susy_particles: [CONSTANTS],
+    excited_fermions: [CONSTANTS],
+    technicolor: [CONSTANTS],
+    dark_matter: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    light_Ieq1_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    light_Ieq0_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    strange_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    charmed_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    bottom_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    ccbar_mesons: [CONSTANTS],
+    #[cfg(feature = "mesons")]
+    bbbar_mesons: [CONSTANTS],
+    #[cfg(feature = "baryons")]
+    light_baryons: [CONSTANTS, EXTRA_CONSTANTS],
+    #[cfg(feature = "baryons")]
+    strange_baryons: [CONSTANTS],
+    #[cfg(feature = "baryons")]
+    charmed_baryons: [CONSTANTS],
+    #[cfg(feature = "baryons")]
+    bottom_baryons: [CONSTANTS],
+    #[cfg(feature = "exotics")]
+    pentaquarks: [CONSTANTS],
+    anti_quarks: [CONSTANTS, EXTRA_CONSTANTS],
+    anti_leptons: [CONSTANTS, EXTRA_CONSTANTS],
+    anti_gauge_and_higgs_bosons: [EXTRA_CONSTANTS],
+    #[cfg(feature = "baryons")]
+    light_anti_baryons: [EXTRA_CONSTANTS],
+    #[cfg(feature = "susy")]
+    susy_anti_particles: [EXTRA_CONSTANTS],
+);
+
+// Modules that re-export the constants of other modules
+const AGGREGATE_MODULES: &[(&str, &[&str])] = &[
+    ("gauge_and_higgs_bosons", &["gauge_bosons", "higgs_bosons"]),
+    #[cfg(feature = "mesons")]
+    ("light_mesons", &["light_Ieq0_mesons", "light_Ieq1_mesons"]),
+    #[cfg(feature = "mesons")]
+    (
+        "mesons",
+        &[
+            "bbbar_mesons",
+            "bottom_mesons",
+            "ccbar_mesons",
+            "charmed_mesons",
+            "light_mesons",
+            "strange_mesons",
+        ],
+    ),
+    #[cfg(feature = "baryons")]
+    (
+        "baryons",
+        &[
+            "bottom_baryons",
+            "charmed_baryons",
+            "light_baryons",
+            "strange_baryons",
+        ],
+    ),
+    (
+        "sm_elementary_particles",
+        &[
+            "anti_gauge_and_higgs_bosons",
+            "anti_leptons",
+            "anti_quarks",
+            "gauge_and_higgs_bosons",
+            "leptons",
+            "quarks",
+        ],
+    ),
+];
+
+/// Generate a C++ header with all particle constants
+pub fn cpp_header() -> String {
+    let mut header = Vec::new();
+    write_cpp_header(&mut header).unwrap();
+    String::from_utf8(header).unwrap()
+}
+
+/// Write a C++ header with all particle constants
+///
+/// See [cpp_header].
+pub fn write_cpp_header<W: Write>(mut w: W) -> io::Result<()> {
+    writeln!(
+        w,
+        "// Generated by particle_id {}. Do not edit.",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(w, "#pragma once")?;
+    writeln!(w)?;
+    writeln!(w, "namespace particle_id {{")?;
+    for (module, constants) in MODULES {
+        writeln!(w)?;
+        writeln!(w, "namespace {module} {{")?;
+        for (name, particle) in constants.iter().copied().flatten() {
+            writeln!(w, "constexpr int {name} = {};", particle.id())?;
+        }
+        writeln!(w, "}} // namespace {module}")?;
+    }
+    for (module, parts) in AGGREGATE_MODULES {
+        writeln!(w)?;
+        writeln!(w, "namespace {module} {{")?;
+        for part in *parts {
+            writeln!(w, "using namespace {part};")?;
+        }
+        writeln!(w, "}} // namespace {module}")?;
+    }
+    writeln!(w)?;
+    writeln!(w, "constexpr const char* name(int id) {{")?;
+    writeln!(w, "  switch (id) {{")?;
+    for particle in (0..2 * NUM_KNOWN).map(from_slot) {
+        if let Some(name) = particle.name() {
+            writeln!(
+                w,
+                "  case {}: return {};",
+                particle.id(),
+                string_literal(name)
+            )?;
+        }
+    }
+    writeln!(w, "  default: return nullptr;")?;
+    writeln!(w, "  }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "}} // namespace particle_id")
+}
+
+fn string_literal(s: &str) -> String {
+    let mut literal = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => write!(literal, "\\{c}").unwrap(),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
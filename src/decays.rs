@@ -0,0 +1,258 @@
+use crate::baryons::*;
+use crate::mesons::*;
+use crate::sm_elementary_particles::*;
+use crate::ParticleID;
+
+// Charge conjugate of a decay final state
+const fn conjugate<const N: usize>(
+    mut products: [ParticleID; N],
+) -> [ParticleID; N] {
+    let mut i = 0;
+    while i < N {
+        if !products[i].is_own_anti_particle() {
+            products[i] = products[i].anti();
+        }
+        i += 1;
+    }
+    products
+}
+
+macro_rules! decay_table {
+    ($($particle:expr => [$(($br:expr, [$($product:expr),+ $(,)?])),+ $(,)?],)*) => {
+        impl ParticleID {
+            /// Known decay channels
+            ///
+            /// Each entry consists of the branching fraction and the
+            /// decay products. Only the main channels of common
+            /// unstable particles are included, so the branching
+            /// fractions need not add up to one. Values are taken
+            /// from the [Review of Particle
+            /// Physics](https://pdg.lbl.gov). Returns an empty slice if
+            /// no decays are known.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use particle_id::sm_elementary_particles::*;
+            /// use particle_id::light_mesons::*;
+            /// let channels = π_plus.decay_channels();
+            /// assert_eq!(channels[0].1, [mu_plus, nu_mu]);
+            /// let channels = π_plus.anti().decay_channels();
+            /// assert_eq!(channels[0].1, [muon, nu_mu_bar]);
+            /// assert!(electron.decay_channels().is_empty());
+            /// ```
+            pub const fn decay_channels(
+                &self,
+            ) -> &'static [(f64, &'static [ParticleID])] {
+                $(
+                    if self.0 == $particle.0 {
+                        const CHANNELS: &[(f64, &[ParticleID])] =
+                            &[$(($br, &[$($product),+])),+];
+                        return CHANNELS;
+                    }
+                    if self.0 == -$particle.0
+                        && !$particle.is_own_anti_particle()
+                    {
+                        const CHANNELS: &[(f64, &[ParticleID])] =
+                            &[$(($br, &conjugate([$($product),+]))),+];
+                        return CHANNELS;
+                    }
+                )*
+                &[]
+            }
+        }
+    };
+}
+
+decay_table!(
+    μ => [(1.0, [e, ν_e_bar, ν_μ])],
+    τ => [
+        (0.2549, [π_plus.anti(), π_0, ν_τ]),
+        (0.1782, [e, ν_e_bar, ν_τ]),
+        (0.1739, [μ, ν_μ_bar, ν_τ]),
+        (0.1082, [π_plus.anti(), ν_τ]),
+        (0.0926, [π_plus.anti(), π_0, π_0, ν_τ]),
+        (0.0899, [π_plus.anti(), π_plus.anti(), π_plus, ν_τ]),
+        (0.00696, [K_plus.anti(), ν_τ]),
+    ],
+    // hadronic W decays: total branching fraction 0.6741, split
+    // according to the squared CKM matrix elements
+    W_plus => [
+        (0.3203, [u, d_bar]),
+        (0.3198, [c, s_bar]),
+        (0.1138, [τ_bar, ν_τ]),
+        (0.1071, [e_bar, ν_e]),
+        (0.1063, [μ_bar, ν_μ]),
+        (0.0169, [u, s_bar]),
+        (0.0164, [c, d_bar]),
+        (0.00057, [c, b_bar]),
+    ],
+    Z => [
+        (0.156, [d, d_bar]),
+        (0.156, [s, s_bar]),
+        (0.1512, [b, b_bar]),
+        (0.1203, [c, c_bar]),
+        (0.116, [u, u_bar]),
+        (0.0667, [ν_e, ν_e_bar]),
+        (0.0667, [ν_μ, ν_μ_bar]),
+        (0.0667, [ν_τ, ν_τ_bar]),
+        (0.03370, [τ, τ_bar]),
+        (0.03366, [μ, μ_bar]),
+        (0.03363, [e, e_bar]),
+    ],
+    h => [
+        (0.5809, [b, b_bar]),
+        (0.2152, [W_plus, W_minus]),
+        (0.0818, [g, g]),
+        (0.06256, [τ, τ_bar]),
+        (0.02884, [c, c_bar]),
+        (0.02641, [Z, Z]),
+        (0.00227, [γ, γ]),
+        (0.001541, [Z, γ]),
+        (0.0002171, [μ, μ_bar]),
+    ],
+    π_plus => [
+        (0.99988, [μ_bar, ν_μ]),
+        (1.23e-4, [e_bar, ν_e]),
+    ],
+    π_0 => [
+        (0.98823, [γ, γ]),
+        (0.01174, [e, e_bar, γ]),
+    ],
+    η => [
+        (0.3936, [γ, γ]),
+        (0.3257, [π_0, π_0, π_0]),
+        (0.2292, [π_plus, π_plus.anti(), π_0]),
+        (0.0422, [π_plus, π_plus.anti(), γ]),
+    ],
+    ρ_770_0 => [(1.0, [π_plus, π_plus.anti()])],
+    ρ_770_plus => [(1.0, [π_plus, π_0])],
+    ω_782 => [
+        (0.892, [π_plus, π_plus.anti(), π_0]),
+        (0.0840, [π_0, γ]),
+        (0.0153, [π_plus, π_plus.anti()]),
+    ],
+    η_prime_958 => [
+        (0.425, [π_plus, π_plus.anti(), η]),
+        (0.289, [ρ_770_0, γ]),
+        (0.224, [π_0, π_0, η]),
+        (0.0252, [ω_782, γ]),
+        (0.0231, [γ, γ]),
+    ],
+    φ_1020 => [
+        (0.491, [K_plus, K_plus.anti()]),
+        (0.339, [K_0_L, K_0_S]),
+        (0.1524, [π_plus, π_plus.anti(), π_0]),
+        (0.01303, [η, γ]),
+    ],
+    K_plus => [
+        (0.6356, [μ_bar, ν_μ]),
+        (0.2067, [π_plus, π_0]),
+        (0.05583, [π_plus, π_plus, π_plus.anti()]),
+        (0.0507, [π_0, e_bar, ν_e]),
+        (0.03352, [π_0, μ_bar, ν_μ]),
+        (0.01760, [π_plus, π_0, π_0]),
+    ],
+    K_0_S => [
+        (0.6920, [π_plus, π_plus.anti()]),
+        (0.3069, [π_0, π_0]),
+    ],
+    K_0_L => [
+        (0.20275, [π_plus.anti(), e_bar, ν_e]),
+        (0.20275, [π_plus, e, ν_e_bar]),
+        (0.1952, [π_0, π_0, π_0]),
+        (0.1352, [π_plus.anti(), μ_bar, ν_μ]),
+        (0.1352, [π_plus, μ, ν_μ_bar]),
+        (0.1254, [π_plus, π_plus.anti(), π_0]),
+    ],
+    K_star_892_plus => [
+        (0.666, [K_0, π_plus]),
+        (0.333, [K_plus, π_0]),
+    ],
+    D_0 => [(0.03947, [K_plus.anti(), π_plus])],
+    D_plus => [(0.0938, [K_plus.anti(), π_plus, π_plus])],
+    Jψ_1S => [
+        (0.05971, [e, e_bar]),
+        (0.05961, [μ, μ_bar]),
+    ],
+    Υ_1S => [
+        (0.0260, [τ, τ_bar]),
+        (0.0248, [μ, μ_bar]),
+        (0.0238, [e, e_bar]),
+    ],
+    n => [(1.0, [p, e, ν_e_bar])],
+    Δ_plus_plus => [(1.0, [p, π_plus])],
+    Λ => [
+        (0.641, [p, π_plus.anti()]),
+        (0.358, [n, π_0]),
+    ],
+    Σ_plus => [
+        (0.5157, [p, π_0]),
+        (0.4831, [n, π_plus]),
+    ],
+    Σ_0 => [(1.0, [Λ, γ])],
+    Σ_minus => [(0.99848, [n, π_plus.anti()])],
+    Ξ_0 => [(0.99524, [Λ, π_0])],
+    Ξ_minus => [(0.99887, [Λ, π_plus.anti()])],
+    Ω_minus => [
+        (0.678, [Λ, K_plus.anti()]),
+        (0.236, [Ξ_0, π_plus.anti()]),
+        (0.0855, [Ξ_minus, π_0]),
+    ],
+);
+
+impl ParticleID {
+    /// Decay channel with the largest known branching fraction
+    ///
+    /// Only the channels listed in
+    /// [decay_channels](Self::decay_channels) are considered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// let (br, products) = Higgs.dominant_decay().unwrap();
+    /// assert_eq!(products, [b, b_bar]);
+    /// assert!(br > 0.5);
+    /// assert_eq!(W_plus.dominant_decay().unwrap().1, [u, d_bar]);
+    /// assert!(electron.dominant_decay().is_none());
+    /// ```
+    pub const fn dominant_decay(&self) -> Option<(f64, &'static [ParticleID])> {
+        let channels = self.decay_channels();
+        if channels.is_empty() {
+            return None;
+        }
+        let mut dominant = channels[0];
+        let mut i = 1;
+        while i < channels.len() {
+            if channels[i].0 > dominant.0 {
+                dominant = channels[i];
+            }
+            i += 1;
+        }
+        Some(dominant)
+    }
+
+    /// Check if the given final state is a known decay channel
+    ///
+    /// The order of the products does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(Z.decays_to(&[mu_plus, muon]));
+    /// assert!(!Z.decays_to(&[mu_plus, electron]));
+    /// assert!(Z.decays_to(&[nu_e_bar, nu_e]));
+    /// assert!(W_plus.decays_to(&[d_bar, u]));
+    /// ```
+    pub fn decays_to(&self, products: &[ParticleID]) -> bool {
+        let mut products = products.to_vec();
+        products.sort_unstable();
+        self.decay_channels().iter().any(|(_, channel)| {
+            let mut channel = channel.to_vec();
+            channel.sort_unstable();
+            channel == products
+        })
+    }
+}
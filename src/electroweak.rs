@@ -0,0 +1,78 @@
+use crate::ParticleID;
+
+// Weak mixing angle sin²θ_W in the MS-bar scheme at the Z mass
+#[cfg(feature = "masses")]
+const SIN2_THETA_W: f64 = 0.23129;
+
+/// Vector and axial-vector couplings to the Z boson
+///
+/// The couplings are normalized such that the Z-fermion vertex is
+/// proportional to γ^μ (g_V - g_A γ⁵).
+#[cfg(feature = "masses")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct ZCouplings {
+    /// Vector coupling g_V
+    pub vector: f64,
+    /// Axial-vector coupling g_A
+    pub axial: f64,
+}
+
+impl ParticleID {
+    /// Third component of the weak isospin times two
+    ///
+    /// The weak isospin refers to the left-handed component of
+    /// elementary fermions and the right-handed component of
+    /// elementary anti-fermions. Returns `None` for all other
+    /// particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(up.t3_times_two(), Some(1));
+    /// assert_eq!(electron.t3_times_two(), Some(-1));
+    /// assert_eq!(positron.t3_times_two(), Some(1));
+    /// assert_eq!(photon.t3_times_two(), None);
+    /// ```
+    pub const fn t3_times_two(&self) -> Option<i32> {
+        let abs = self.abs();
+        if !abs.is_quark() && !abs.is_lepton() {
+            return None;
+        }
+        let t3 = if abs.0 % 2 == 0 { 1 } else { -1 };
+        Some(self.0.signum() * t3)
+    }
+
+    /// Couplings of an elementary fermion to the Z boson
+    ///
+    /// The couplings are g_V = T₃ - 2 Q sin²θ_W and g_A = T₃, with
+    /// sin²θ_W in the MS-bar scheme at the Z mass. For anti-fermions,
+    /// both couplings change sign. Returns `None` for all particles
+    /// other than elementary fermions. Requires the `masses` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// let couplings = electron.z_couplings().unwrap();
+    /// assert!((couplings.vector + 0.0374).abs() < 1e-4);
+    /// assert_eq!(couplings.axial, -0.5);
+    /// let couplings = nu_e.z_couplings().unwrap();
+    /// assert_eq!(couplings.vector, 0.5);
+    /// assert!(photon.z_couplings().is_none());
+    /// ```
+    #[cfg(feature = "masses")]
+    pub const fn z_couplings(&self) -> Option<ZCouplings> {
+        let (Some(t3_times_two), Some(charge_in_thirds)) =
+            (self.t3_times_two(), self.charge_in_thirds())
+        else {
+            return None;
+        };
+        let t3 = t3_times_two as f64 / 2.;
+        let charge = charge_in_thirds as f64 / 3.;
+        Some(ZCouplings {
+            vector: t3 - 2. * charge * SIN2_THETA_W,
+            axial: t3,
+        })
+    }
+}
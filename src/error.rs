@@ -0,0 +1,40 @@
+use std::fmt::{self, Display};
+
+use crate::vertex::ConservationLaw;
+use crate::ParticleID;
+
+/// Errors returned by fallible operations in this crate
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// The given string could not be parsed as a particle
+    Parse(String),
+    /// The code is not valid in the Monte Carlo Particle Numbering Scheme
+    InvalidCode(i32),
+    /// The particle is valid, but not known to this crate
+    UnknownParticle(ParticleID),
+    /// The number is outside the range of particle codes
+    OutOfRange(i64),
+    /// A conservation law is violated
+    NotConserved(ConservationLaw),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(s) => write!(f, "Failed to parse '{s}' as particle"),
+            Error::InvalidCode(id) => write!(f, "Invalid particle code {id}"),
+            Error::UnknownParticle(p) => {
+                write!(f, "Unknown particle with code {}", p.id())
+            }
+            Error::OutOfRange(n) => {
+                write!(f, "{n} is out of range for a particle code")
+            }
+            Error::NotConserved(law) => {
+                write!(f, "Violation of {law} conservation")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,130 @@
+use crate::ParticleID;
+
+/// Flavor quantum numbers
+///
+/// The signs follow the usual convention that the flavor of a quark
+/// has the same sign as its charge, e.g. the strange quark has
+/// strangeness -1.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct FlavorNumbers {
+    /// Twice the third component of isospin
+    pub i3_times_two: i32,
+    /// Strangeness
+    pub s: i32,
+    /// Charm
+    pub c: i32,
+    /// Bottomness
+    pub b: i32,
+    /// Topness
+    pub t: i32,
+}
+
+impl FlavorNumbers {
+    /// Third component of isospin
+    pub fn i3(&self) -> f64 {
+        self.i3_times_two as f64 / 2.
+    }
+}
+
+/// Lepton flavors
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LeptonFlavor {
+    /// Electron flavor
+    Electron,
+    /// Muon flavor
+    Muon,
+    /// Tau flavor
+    Tau,
+    /// Fourth-generation tau' flavor
+    TauPrime,
+}
+
+impl LeptonFlavor {
+    const fn from_index(idx: u32) -> Self {
+        match idx {
+            0 => LeptonFlavor::Electron,
+            1 => LeptonFlavor::Muon,
+            2 => LeptonFlavor::Tau,
+            _ => LeptonFlavor::TauPrime,
+        }
+    }
+}
+
+impl ParticleID {
+    /// Lepton number for the given flavor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::LeptonFlavor;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(muon.lepton_number(LeptonFlavor::Muon), 1);
+    /// assert_eq!(nu_mu_bar.lepton_number(LeptonFlavor::Muon), -1);
+    /// assert_eq!(muon.lepton_number(LeptonFlavor::Electron), 0);
+    /// assert_eq!(photon.lepton_number(LeptonFlavor::Tau), 0);
+    /// ```
+    pub const fn lepton_number(&self, flavor: LeptonFlavor) -> i32 {
+        self.lepton_flavor_numbers()[flavor as usize]
+    }
+
+    /// Flavor of a neutrino or anti-neutrino
+    ///
+    /// Returns `None` if this is neither a neutrino nor an
+    /// anti-neutrino.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::LeptonFlavor;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(nu_tau.neutrino_flavor(), Some(LeptonFlavor::Tau));
+    /// assert_eq!(nu_e_bar.neutrino_flavor(), Some(LeptonFlavor::Electron));
+    /// assert_eq!(electron.neutrino_flavor(), None);
+    /// ```
+    pub const fn neutrino_flavor(&self) -> Option<LeptonFlavor> {
+        if !self.abs().is_neutrino() {
+            return None;
+        }
+        Some(LeptonFlavor::from_index((self.0.unsigned_abs() - 12) / 2))
+    }
+
+    /// Check if this is a neutrino of the given flavor
+    ///
+    /// Like for [is_neutrino](Self::is_neutrino), anti-neutrinos are
+    /// not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::LeptonFlavor;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(nu_mu.is_neutrino_of(LeptonFlavor::Muon));
+    /// assert!(!nu_mu.is_neutrino_of(LeptonFlavor::Electron));
+    /// assert!(!nu_mu_bar.is_neutrino_of(LeptonFlavor::Muon));
+    /// ```
+    pub const fn is_neutrino_of(&self, flavor: LeptonFlavor) -> bool {
+        self.is_neutrino()
+            && matches!(self.neutrino_flavor(), Some(f) if f as u32 == flavor as u32)
+    }
+
+    /// Charged lepton in the same weak isospin doublet as a neutrino
+    ///
+    /// Neutrinos are mapped to negatively charged leptons and
+    /// anti-neutrinos to positively charged leptons. Returns `None`
+    /// if this is neither a neutrino nor an anti-neutrino.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(nu_mu.charged_partner(), Some(muon));
+    /// assert_eq!(nu_e_bar.charged_partner(), Some(positron));
+    /// assert_eq!(muon.charged_partner(), None);
+    /// ```
+    pub const fn charged_partner(&self) -> Option<ParticleID> {
+        if !self.abs().is_neutrino() {
+            return None;
+        }
+        Some(ParticleID(self.0 - self.0.signum()))
+    }
+}
@@ -0,0 +1,80 @@
+//! Quark flavor flow between the external legs of a process
+//!
+//! A flavor line starts at a quark and ends at an anti-quark of the
+//! same flavor, where incoming particles are treated as outgoing
+//! anti-particles. Thus, an incoming quark can be connected to an
+//! outgoing quark or to an incoming anti-quark of the same flavor.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::flow::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! // u d̄ → W⁺ g
+//! assert_eq!(
+//!     flavor_flow_pairs(&[u, d_bar], &[W_plus, g]),
+//!     []
+//! );
+//!
+//! // u ū → d d̄
+//! assert_eq!(
+//!     flavor_flow_pairs(&[u, u_bar], &[d, d_bar]),
+//!     [
+//!         (Leg::Incoming(1), Leg::Incoming(0)),
+//!         (Leg::Outgoing(0), Leg::Outgoing(1)),
+//!     ]
+//! );
+//!
+//! // u g → u g
+//! assert_eq!(
+//!     flavor_flow_pairs(&[u, g], &[u, g]),
+//!     [(Leg::Outgoing(0), Leg::Incoming(0))]
+//! );
+//! ```
+use crate::ParticleID;
+
+/// External leg of a process
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Leg {
+    /// Incoming particle with the given position
+    Incoming(usize),
+    /// Outgoing particle with the given position
+    Outgoing(usize),
+}
+
+/// All pairs of legs that can be connected by a quark flavor line
+///
+/// The first leg of each pair is the one where the flavor line
+/// starts, i.e. an incoming anti-quark or an outgoing quark. Legs
+/// can appear in more than one pair if the assignment is
+/// ambiguous.
+pub fn flavor_flow_pairs(
+    incoming: &[ParticleID],
+    outgoing: &[ParticleID],
+) -> Vec<(Leg, Leg)> {
+    // all particles as outgoing
+    let legs: Vec<_> = incoming
+        .iter()
+        .enumerate()
+        .map(|(n, p)| (Leg::Incoming(n), p.anti()))
+        .chain(
+            outgoing
+                .iter()
+                .enumerate()
+                .map(|(n, p)| (Leg::Outgoing(n), *p)),
+        )
+        .collect();
+    let mut pairs = Vec::new();
+    for &(start, q) in &legs {
+        if !q.is_quark() {
+            continue;
+        }
+        for &(end, qbar) in &legs {
+            if qbar == q.anti() {
+                pairs.push((start, end));
+            }
+        }
+    }
+    pairs
+}
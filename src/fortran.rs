@@ -0,0 +1,101 @@
+//! Interoperability with Fortran code
+//!
+//! Fortran event generators usually store particle codes
+//! ("KF codes") in `INTEGER*4` arrays and names in blank-padded
+//! `CHARACTER*N` variables, often in common blocks. The functions in
+//! this module convert between these representations and
+//! [ParticleID]s.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::fortran::*;
+//! use particle_id::NameStyle;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! // INTEGER*4 KF(4)
+//! let mut kf = [0; 4];
+//! assert_eq!(fill_kf_codes(&[electron, positron], &mut kf), 2);
+//! assert_eq!(kf, [11, -11, 0, 0]);
+//! assert_eq!(from_kf_codes(&kf[..2]), [electron, positron]);
+//!
+//! // CHARACTER*8 NAME
+//! let name: [u8; 8] = fortran_name(positron, NameStyle::Ascii).unwrap();
+//! assert_eq!(&name, b"e+      ");
+//! assert_eq!(from_fortran_name(&name), Some(positron));
+//! ```
+use crate::{NameStyle, ParticleID};
+
+/// Convert particles to KF codes
+pub fn kf_codes(particles: &[ParticleID]) -> Vec<i32> {
+    particles.iter().map(|p| p.id()).collect()
+}
+
+/// Convert KF codes to particles
+pub fn from_kf_codes(codes: &[i32]) -> Vec<ParticleID> {
+    codes.iter().map(|&id| ParticleID::new(id)).collect()
+}
+
+/// Write KF codes into a fixed-size array
+///
+/// As many particles as fit are written to the beginning of `codes`,
+/// and the remaining entries are set to zero. Returns the number of
+/// written particles.
+pub fn fill_kf_codes(particles: &[ParticleID], codes: &mut [i32]) -> usize {
+    let len = particles.len().min(codes.len());
+    let (written, rest) = codes.split_at_mut(len);
+    for (code, particle) in written.iter_mut().zip(particles) {
+        *code = particle.id();
+    }
+    rest.fill(0);
+    len
+}
+
+/// Pad a name with blanks to a `CHARACTER*N` value
+///
+/// Returns `None` if the name contains non-ASCII characters or is
+/// longer than `N`.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::fortran::pad_name;
+/// assert_eq!(pad_name::<4>("pi+"), Some(*b"pi+ "));
+/// assert_eq!(pad_name::<2>("pi+"), None);
+/// assert_eq!(pad_name::<4>("π⁺"), None);
+/// ```
+pub fn pad_name<const N: usize>(name: &str) -> Option<[u8; N]> {
+    if !name.is_ascii() || name.len() > N {
+        return None;
+    }
+    let mut padded = [b' '; N];
+    padded[..name.len()].copy_from_slice(name.as_bytes());
+    Some(padded)
+}
+
+/// Particle name in the given style as a `CHARACTER*N` value
+///
+/// Returns `None` if the particle has no name in this style or the
+/// name cannot be [padded](pad_name).
+pub fn fortran_name<const N: usize>(
+    particle: ParticleID,
+    style: NameStyle,
+) -> Option<[u8; N]> {
+    particle.render_name(style).and_then(pad_name)
+}
+
+/// Parse a `CHARACTER` value as a particle
+///
+/// Trailing blanks and null characters are ignored. Returns `None` if
+/// the name is not valid ASCII or cannot be parsed.
+pub fn from_fortran_name(name: &[u8]) -> Option<ParticleID> {
+    let len = name
+        .iter()
+        .rposition(|&c| c != b' ' && c != 0)
+        .map_or(0, |pos| pos + 1);
+    let name = std::str::from_utf8(&name[..len]).ok()?;
+    if !name.is_ascii() {
+        return None;
+    }
+    name.parse().ok()
+}
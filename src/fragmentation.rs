@@ -0,0 +1,82 @@
+use crate::ParticleID;
+
+/// Hadron species as binned in fragmentation function data
+///
+/// Anti-particles belong to the same class as the corresponding
+/// particles.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum FragmentationClass {
+    /// π⁺ and π⁻
+    ChargedPion,
+    /// π⁰
+    NeutralPion,
+    /// K⁺ and K⁻
+    ChargedKaon,
+    /// K⁰, K̄⁰, K⁰_L, and K⁰_S
+    NeutralKaon,
+    /// η
+    Eta,
+    /// Protons and anti-protons
+    Proton,
+    /// Λ and anti-Λ
+    Lambda,
+    /// Any other charged hadron
+    OtherCharged,
+    /// Any other neutral hadron
+    OtherNeutral,
+}
+
+impl ParticleID {
+    /// Species bin used in fragmentation function analyses
+    ///
+    /// Returns `None` if this is not a hadron.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::FragmentationClass;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(
+    ///     π_plus.anti().fragmentation_class(),
+    ///     Some(FragmentationClass::ChargedPion)
+    /// );
+    /// assert_eq!(
+    ///     K_0_S.fragmentation_class(),
+    ///     Some(FragmentationClass::NeutralKaon)
+    /// );
+    /// assert_eq!(
+    ///     proton.anti().fragmentation_class(),
+    ///     Some(FragmentationClass::Proton)
+    /// );
+    /// assert_eq!(
+    ///     neutron.fragmentation_class(),
+    ///     Some(FragmentationClass::OtherNeutral)
+    /// );
+    /// assert_eq!(electron.fragmentation_class(), None);
+    /// # }
+    /// ```
+    pub const fn fragmentation_class(&self) -> Option<FragmentationClass> {
+        if !self.has_meson_digits() && !self.has_baryon_digits() {
+            return None;
+        }
+        let class = match self.0.unsigned_abs() {
+            211 => FragmentationClass::ChargedPion,
+            111 => FragmentationClass::NeutralPion,
+            321 => FragmentationClass::ChargedKaon,
+            130 | 310 | 311 => FragmentationClass::NeutralKaon,
+            221 => FragmentationClass::Eta,
+            2212 => FragmentationClass::Proton,
+            3122 => FragmentationClass::Lambda,
+            _ => match self.charge_in_thirds() {
+                Some(0) | None => FragmentationClass::OtherNeutral,
+                Some(_) => FragmentationClass::OtherCharged,
+            },
+        };
+        Some(class)
+    }
+}
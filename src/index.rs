@@ -0,0 +1,314 @@
+// Dense index of all particles known to this crate
+//
+// Particles are indexed by the position of their absolute code in
+// the sorted list of known codes. The index depends on the enabled
+// features.
+use std::sync::OnceLock;
+
+use crate::names::PARSE_STYLES;
+use crate::ParticleID;
+
+#[cfg(feature = "mesons")]
+const MESONS: &[ParticleID] = &crate::mesons::MESONS;
+#[cfg(not(feature = "mesons"))]
+const MESONS: &[ParticleID] = &[];
+
+#[cfg(feature = "baryons")]
+const BARYONS: &[ParticleID] = &crate::baryons::BARYONS;
+#[cfg(not(feature = "baryons"))]
+const BARYONS: &[ParticleID] = &[];
+
+#[cfg(feature = "susy")]
+const SUSY_PARTICLES: &[ParticleID] = &crate::susy_particles::SUSY_PARTICLES;
+#[cfg(not(feature = "susy"))]
+const SUSY_PARTICLES: &[ParticleID] = &[];
+
+#[cfg(feature = "exotics")]
+const PENTAQUARKS: &[ParticleID] = &crate::pentaquarks::PENTAQUARKS;
+#[cfg(not(feature = "exotics"))]
+const PENTAQUARKS: &[ParticleID] = &[];
+
+const PARTS: &[&[ParticleID]] = &[
+    &crate::quarks::QUARKS,
+    &crate::leptons::LEPTONS,
+    &crate::gauge_bosons::GAUGE_BOSONS,
+    &crate::higgs_bosons::HIGGS_BOSONS,
+    &crate::special_particles::SPECIAL_PARTICLES,
+    &crate::diquarks::DIQUARKS,
+    &crate::excited_fermions::EXCITED_FERMIONS,
+    &crate::technicolor::TECHNICOLOR,
+    &crate::dark_matter::DARK_MATTER,
+    SUSY_PARTICLES,
+    MESONS,
+    BARYONS,
+    PENTAQUARKS,
+];
+
+const fn total_len(parts: &[&[ParticleID]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+const fn sorted<const N: usize>(parts: &[&[ParticleID]]) -> [ParticleID; N] {
+    let mut result = [ParticleID(0); N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            // insertion sort
+            let next = parts[i][j];
+            let mut k = n;
+            while k > 0 && result[k - 1].0 > next.0 {
+                result[k] = result[k - 1];
+                k -= 1;
+            }
+            assert!(k == 0 || result[k - 1].0 != next.0, "duplicate code");
+            result[k] = next;
+            n += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Number of known particles, not counting anti-particles
+pub(crate) const NUM_KNOWN: usize = total_len(PARTS);
+
+/// All known particles with positive codes, sorted by code
+pub(crate) const KNOWN: [ParticleID; NUM_KNOWN] = sorted(PARTS);
+
+/// Position of the absolute code in `KNOWN`
+pub(crate) const fn known_index(particle: ParticleID) -> Option<usize> {
+    let id = particle.0.unsigned_abs();
+    let mut lo = 0;
+    let mut hi = NUM_KNOWN;
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let mid_id = KNOWN[mid].0.unsigned_abs();
+        if mid_id == id {
+            return Some(mid);
+        } else if mid_id < id {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    None
+}
+
+/// Dense index distinguishing particles and anti-particles
+pub(crate) const fn slot(particle: ParticleID) -> Option<usize> {
+    match known_index(particle) {
+        Some(idx) => Some(2 * idx + particle.is_anti_particle() as usize),
+        None => None,
+    }
+}
+
+/// Particle corresponding to a dense index
+pub(crate) const fn from_slot(slot: usize) -> ParticleID {
+    let particle = KNOWN[slot / 2];
+    if slot % 2 == 1 {
+        particle.anti()
+    } else {
+        particle
+    }
+}
+
+// Maximum length of a text ID, "PDG_M" followed by ten digits
+const STR_ID_LEN: usize = 15;
+
+// Text IDs of all known particles and their lengths, indexed by slot
+static STR_IDS: [([u8; STR_ID_LEN], usize); 2 * NUM_KNOWN] = str_ids();
+
+const fn str_ids() -> [([u8; STR_ID_LEN], usize); 2 * NUM_KNOWN] {
+    let mut ids = [([0; STR_ID_LEN], 0); 2 * NUM_KNOWN];
+    let mut slot = 0;
+    while slot < ids.len() {
+        let particle = from_slot(slot);
+        let (id, mut len) = (&mut ids[slot].0, 0);
+        let prefix: &[u8] = if particle.0 < 0 { b"PDG_M" } else { b"PDG_" };
+        while len < prefix.len() {
+            id[len] = prefix[len];
+            len += 1;
+        }
+        let code = particle.0.unsigned_abs();
+        let mut pow = 1;
+        while pow <= code / 10 {
+            pow *= 10;
+        }
+        while pow > 0 {
+            id[len] = b'0' + (code / pow % 10) as u8;
+            len += 1;
+            pow /= 10;
+        }
+        ids[slot].1 = len;
+        slot += 1;
+    }
+    ids
+}
+
+impl ParticleID {
+    /// Compact encoding as a small unsigned integer
+    ///
+    /// The encoding consists of the position of the particle in the
+    /// sorted list of all particles known to this crate, shifted left
+    /// by one bit, with the lowest bit set for anti-particles. It is
+    /// only stable for a fixed crate version and set of enabled
+    /// features. Returns `None` for particles not known to this crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(down.to_compact_u32(), Some(0));
+    /// assert_eq!(anti_down.to_compact_u32(), Some(1));
+    /// let compact = positron.to_compact_u32().unwrap();
+    /// assert_eq!(ParticleID::from_compact_u32(compact), Some(positron));
+    /// assert_eq!(ParticleID::new(123456).to_compact_u32(), None);
+    /// ```
+    pub const fn to_compact_u32(&self) -> Option<u32> {
+        match slot(*self) {
+            Some(slot) => Some(slot as u32),
+            None => None,
+        }
+    }
+
+    /// Canonical text identifier
+    ///
+    /// The identifier consists of the prefix "PDG_", followed by "M"
+    /// for negative codes and the absolute value of the code. It is a
+    /// valid identifier in C and Fortran and can be used to generate
+    /// constant or enum definitions. Returns `None` for particles not
+    /// known to this crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(proton.as_str_id(), Some("PDG_2212"));
+    /// assert_eq!(positron.as_str_id(), Some("PDG_M11"));
+    /// assert_eq!(ParticleID::new(123456).as_str_id(), None);
+    /// # }
+    /// ```
+    pub const fn as_str_id(&self) -> Option<&'static str> {
+        let Some(slot) = slot(*self) else {
+            return None;
+        };
+        let (id, len) = &STR_IDS[slot];
+        match std::str::from_utf8(id.split_at(*len).0) {
+            Ok(id) => Some(id),
+            Err(_) => None,
+        }
+    }
+
+    /// Decode a compact encoding
+    ///
+    /// See [to_compact_u32](Self::to_compact_u32). Returns `None` if
+    /// the value does not correspond to any particle.
+    pub const fn from_compact_u32(compact: u32) -> Option<ParticleID> {
+        if (compact as usize) < 2 * NUM_KNOWN {
+            Some(from_slot(compact as usize))
+        } else {
+            None
+        }
+    }
+}
+
+impl ParticleID {
+    /// Iterate over the names and symbols of all known particles
+    ///
+    /// Both the [name](Self::name) and the [symbol](Self::symbol) of
+    /// each particle and anti-particle are included, where available.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let mut names = ParticleID::known_names();
+    /// assert!(names.any(|(name, p)| name == "positron" && p == positron));
+    /// ```
+    pub fn known_names() -> impl Iterator<Item = (&'static str, ParticleID)> {
+        (0..2 * NUM_KNOWN).map(from_slot).flat_map(|p| {
+            let name = p.name();
+            let symbol = p.symbol().filter(|&s| Some(s) != name);
+            name.into_iter().chain(symbol).map(move |n| (n, p))
+        })
+    }
+
+    /// Find all particles with a name or symbol containing `query`
+    ///
+    /// The search ignores case. Each matching particle is only
+    /// returned once, together with the first matching name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let results: Vec<_> = ParticleID::search("Muon").collect();
+    /// assert!(results.contains(&("muon", muon)));
+    /// assert!(results.contains(&("muon neutrino", nu_mu)));
+    /// ```
+    pub fn search(
+        query: &str,
+    ) -> impl Iterator<Item = (&'static str, ParticleID)> {
+        let query = query.to_lowercase();
+        let mut last = None;
+        Self::known_names().filter(move |&(name, p)| {
+            if last == Some(p) || !name.to_lowercase().contains(&query) {
+                return false;
+            }
+            last = Some(p);
+            true
+        })
+    }
+}
+
+// Names of all known particles in any style, sorted by name
+//
+// Entries with the same name are ordered by precedence: the default
+// name, the symbol, then the styles in `PARSE_STYLES` order, and
+// finally by slot.
+static NAMES: OnceLock<Vec<(&'static str, ParticleID)>> = OnceLock::new();
+
+fn names() -> &'static [(&'static str, ParticleID)] {
+    NAMES.get_or_init(|| {
+        let particles = (0..2 * NUM_KNOWN).map(from_slot);
+        let mut names: Vec<_> = particles
+            .flat_map(|p| {
+                let names = [p.name(), p.symbol()].into_iter();
+                let styled =
+                    PARSE_STYLES.into_iter().map(move |s| p.render_name(s));
+                names
+                    .chain(styled)
+                    .enumerate()
+                    .filter_map(move |(rank, n)| Some((n?.trim(), rank, p)))
+            })
+            .collect();
+        // stable sort to keep the slot order for equal precedence
+        names.sort_by_key(|&(n, rank, _)| (n, rank));
+        names.into_iter().map(|(n, _, p)| (n, p)).collect()
+    })
+}
+
+// Find the known particle with the given name in any style
+pub(crate) fn find_by_name(name: &str) -> Option<ParticleID> {
+    let names = names();
+    let pos = names.partition_point(|&(n, _)| n < name);
+    match names.get(pos) {
+        Some(&(n, p)) if n == name => Some(p),
+        _ => None,
+    }
+}
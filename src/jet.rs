@@ -0,0 +1,81 @@
+//! Truth flavor labels of jets
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "mesons")] {
+//! use particle_id::jet::*;
+//! use particle_id::light_mesons::*;
+//! use particle_id::bottom_mesons::*;
+//! use particle_id::charmed_mesons::*;
+//!
+//! assert_eq!(jet_flavor_label([π_plus, D_0, B_plus]), JetFlavor::Bottom);
+//! assert_eq!(jet_flavor_label([π_plus, D_0.anti()]), JetFlavor::Charm);
+//! assert_eq!(jet_flavor_label([π_plus, π_0]), JetFlavor::Light);
+//! assert_eq!(jet_flavor_label([]), JetFlavor::Light);
+//! # }
+//! ```
+use crate::ParticleID;
+
+/// Flavor label of a jet
+///
+/// Labels are ordered by priority, so the label of a jet is the
+/// maximum of the labels of its hadrons.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum JetFlavor {
+    /// Neither bottom nor charm hadrons
+    Light,
+    /// At least one charm hadron, but no bottom hadrons
+    Charm,
+    /// At least one bottom hadron
+    Bottom,
+}
+
+impl JetFlavor {
+    /// Hadron flavor number, i.e. 5 for bottom, 4 for charm, and 0 for light
+    pub const fn hadron_flavor(&self) -> u32 {
+        match self {
+            JetFlavor::Light => 0,
+            JetFlavor::Charm => 4,
+            JetFlavor::Bottom => 5,
+        }
+    }
+
+    /// Label of a jet containing only the given hadron
+    pub const fn of_hadron(hadron: ParticleID) -> Self {
+        let heaviest = if hadron.has_meson_digits() {
+            if hadron.n_q2() > hadron.n_q3() {
+                hadron.n_q2()
+            } else {
+                hadron.n_q3()
+            }
+        } else if hadron.has_baryon_digits() {
+            hadron.n_q1()
+        } else {
+            0
+        };
+        match heaviest {
+            5 => JetFlavor::Bottom,
+            4 => JetFlavor::Charm,
+            _ => JetFlavor::Light,
+        }
+    }
+}
+
+/// Flavor label of a jet with the given associated hadrons
+///
+/// The hadrons are typically the ones clustered into the jet with
+/// ghost association. Jets with at least one bottom hadron are
+/// labelled [JetFlavor::Bottom]. Among the remaining jets, the ones
+/// with at least one charm hadron are labelled [JetFlavor::Charm].
+/// All other jets are [JetFlavor::Light].
+pub fn jet_flavor_label<I>(hadrons: I) -> JetFlavor
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    hadrons
+        .into_iter()
+        .map(JetFlavor::of_hadron)
+        .max()
+        .unwrap_or(JetFlavor::Light)
+}
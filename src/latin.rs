@@ -0,0 +1,923 @@
+//! Constants with Latin names
+//!
+//! This module mirrors the particle constant modules of the crate
+//! root. The constant names only consist of ASCII letters, digits,
+//! and underscores: Greek letters are spelled out, e.g. `π_plus`
+//! becomes `pi_plus` and `Λ_b_0` becomes `Lambda_b_0`. This makes the
+//! names usable for code generators targeting languages without
+//! Unicode identifiers. Constants that already have ASCII names are
+//! included unchanged.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+//! use particle_id::latin::light_mesons::*;
+//! use particle_id::latin::strange_baryons::*;
+//!
+//! assert_eq!(pi_plus, particle_id::light_mesons::π_plus);
+//! assert_eq!(Lambda.id(), 3122);
+//! # }
+//! ```
+// The constants are checked against the original modules at compile
+// time, see `is_mirror`.
+use crate::ParticleID;
+
+macro_rules! latin_constants {
+    (
+        $module:ident[$($constants:ident),+]:
+        $($latin:ident = $original:ident,)*
+    ) => {
+        $(
+            #[doc = concat!(
+                "Same as [", stringify!($original), "](crate::",
+                stringify!($module), "::", stringify!($original), ")"
+            )]
+            pub const $latin: ParticleID = crate::$module::$original;
+        )*
+
+        const _: () = assert!(super::is_mirror(
+            &[$((stringify!($latin), stringify!($original)),)*],
+            &[$(&crate::$module::$constants),+],
+        ));
+    };
+}
+
+// Check that the `latin` constants mirror the `original` ones
+//
+// Each entry of `latin` is a pair of the Latin name and the name of
+// the original constant. Every original constant has to be mirrored
+// by a constant whose name is the Latin spelling of the original
+// name. Original constants with the same Latin spelling, like `μ` and
+// `mu`, are mirrored by a single constant.
+const fn is_mirror(
+    latin: &[(&str, &str)],
+    original: &[&[(&str, ParticleID)]],
+) -> bool {
+    let mut i = 0;
+    while i < latin.len() {
+        if !is_latin_spelling(latin[i].0, latin[i].1) {
+            return false;
+        }
+        i += 1;
+    }
+    let mut module = 0;
+    while module < original.len() {
+        let mut j = 0;
+        while j < original[module].len() {
+            let name = original[module][j].0;
+            let mut i = 0;
+            while i < latin.len() && !is_latin_spelling(latin[i].0, name) {
+                i += 1;
+            }
+            if i == latin.len() {
+                return false;
+            }
+            j += 1;
+        }
+        module += 1;
+    }
+    true
+}
+
+// Check if `latin` is `original` with all Greek letters spelled out
+const fn is_latin_spelling(latin: &str, original: &str) -> bool {
+    let latin = latin.as_bytes();
+    let original = original.as_bytes();
+    let mut i = 0;
+    let mut j = 0;
+    while j < original.len() {
+        if original[j].is_ascii() {
+            if i >= latin.len() || latin[i] != original[j] {
+                return false;
+            }
+            i += 1;
+            j += 1;
+            continue;
+        }
+        // Greek letters take up two bytes in UTF-8
+        if j + 1 >= original.len() {
+            return false;
+        }
+        let c = ((original[j] as u32 & 0x1f) << 6)
+            | (original[j + 1] as u32 & 0x3f);
+        let Some(spelling) = greek_spelling(c) else {
+            return false;
+        };
+        let spelling = spelling.as_bytes();
+        let mut k = 0;
+        while k < spelling.len() {
+            if i >= latin.len() || latin[i] != spelling[k] {
+                return false;
+            }
+            i += 1;
+            k += 1;
+        }
+        j += 2;
+    }
+    i == latin.len()
+}
+
+const fn greek_spelling(c: u32) -> Option<&'static str> {
+    let Some(c) = char::from_u32(c) else {
+        return None;
+    };
+    let spelling = match c {
+        'Δ' => "Delta",
+        'Θ' => "Theta",
+        'Λ' => "Lambda",
+        'Ξ' => "Xi",
+        'Σ' => "Sigma",
+        'Υ' => "Upsilon",
+        'Φ' => "Phi",
+        'Ω' => "Omega",
+        'γ' => "gamma",
+        'η' => "eta",
+        'μ' => "mu",
+        'ν' => "nu",
+        'π' => "pi",
+        'ρ' => "rho",
+        'τ' => "tau",
+        'φ' => "phi",
+        'χ' => "chi",
+        'ψ' => "psi",
+        'ω' => "omega",
+        _ => return None,
+    };
+    Some(spelling)
+}
+
+pub mod quarks {
+    use super::*;
+
+    latin_constants!(
+        quarks[CONSTANTS, EXTRA_CONSTANTS]:
+        d = d,
+        u = u,
+        s = s,
+        c = c,
+        b = b,
+        t = t,
+        b_prime = b_prime,
+        t_prime = t_prime,
+        down = down,
+        up = up,
+        strange = strange,
+        charm = charm,
+        bottom = bottom,
+        top = top,
+    );
+}
+
+pub mod leptons {
+    use super::*;
+
+    latin_constants!(
+        leptons[CONSTANTS, EXTRA_CONSTANTS]:
+        e = e,
+        nu_e = ν_e,
+        mu = μ,
+        nu_mu = ν_μ,
+        tau = τ,
+        nu_tau = ν_τ,
+        tau_prime = τ_prime,
+        nu_tau_prime = ν_τ_prime,
+        electron = electron,
+        electron_neutrino = electron_neutrino,
+        muon = muon,
+        muon_neutrino = muon_neutrino,
+        tau_neutrino = tau_neutrino,
+        tau_prime_neutrino = tau_prime_neutrino,
+    );
+}
+
+pub mod gauge_bosons {
+    use super::*;
+
+    latin_constants!(
+        gauge_bosons[CONSTANTS, EXTRA_CONSTANTS]:
+        g = g,
+        gamma = γ,
+        Z = Z,
+        W_plus = W_plus,
+        Z_prime = Z_prime,
+        Z_prime_prime = Z_prime_prime,
+        W_prime = W_prime,
+        gluon = gluon,
+        photon = photon,
+    );
+}
+
+pub mod higgs_bosons {
+    use super::*;
+
+    latin_constants!(
+        higgs_bosons[CONSTANTS, EXTRA_CONSTANTS]:
+        h = h,
+        H0 = H0,
+        A0 = A0,
+        H_plus = H_plus,
+        H_plus_plus = H_plus_plus,
+        a0 = a0,
+        H = H,
+        Higgs = Higgs,
+        H_0 = H_0,
+        A_0 = A_0,
+        a_0 = a_0,
+    );
+}
+
+pub mod gauge_and_higgs_bosons {
+    pub use super::gauge_bosons::*;
+    pub use super::higgs_bosons::*;
+}
+
+pub mod special_particles {
+    use super::*;
+
+    latin_constants!(
+        special_particles[CONSTANTS, EXTRA_CONSTANTS]:
+        G = G,
+        R_0 = R_0,
+        LQ_c = LQ_c,
+        reggeon = reggeon,
+        pomeron = pomeron,
+        odderon = odderon,
+        graviton = graviton,
+    );
+}
+
+pub mod diquarks {
+    use super::*;
+
+    latin_constants!(
+        diquarks[CONSTANTS]:
+        dd_1 = dd_1,
+        ud_0 = ud_0,
+        ud_1 = ud_1,
+        uu_1 = uu_1,
+        sd_0 = sd_0,
+        sd_1 = sd_1,
+        su_0 = su_0,
+        su_1 = su_1,
+        ss_1 = ss_1,
+        cd_0 = cd_0,
+        cd_1 = cd_1,
+        cu_0 = cu_0,
+        cu_1 = cu_1,
+        cs_0 = cs_0,
+        cs_1 = cs_1,
+        cc_1 = cc_1,
+        bd_0 = bd_0,
+        bd_1 = bd_1,
+        bu_0 = bu_0,
+        bu_1 = bu_1,
+        bs_0 = bs_0,
+        bs_1 = bs_1,
+        bc_0 = bc_0,
+        bc_1 = bc_1,
+        bb_1 = bb_1,
+    );
+}
+
+#[cfg(feature = "susy")]
+pub mod susy_particles {
+    use super::*;
+
+    latin_constants!(
+        susy_particles[CONSTANTS]:
+        d_tilde_L = d_tilde_L,
+        u_tilde_L = u_tilde_L,
+        s_tilde_L = s_tilde_L,
+        c_tilde_L = c_tilde_L,
+        b_tilde_1 = b_tilde_1,
+        t_tilde_1 = t_tilde_1,
+        e_tilde_L = e_tilde_L,
+        nu_e_tilde_L = ν_e_tilde_L,
+        mu_tilde_L = μ_tilde_L,
+        nu_mu_tilde_L = ν_μ_tilde_L,
+        tau_tilde_1 = τ_tilde_1,
+        nu_tau_tilde_L = ν_τ_tilde_L,
+        d_tilde_R = d_tilde_R,
+        u_tilde_R = u_tilde_R,
+        s_tilde_R = s_tilde_R,
+        c_tilde_R = c_tilde_R,
+        b_tilde_2 = b_tilde_2,
+        t_tilde_2 = t_tilde_2,
+        e_tilde_R = e_tilde_R,
+        mu_tilde_R = μ_tilde_R,
+        tau_tilde_2 = τ_tilde_2,
+        g_tilde = g_tilde,
+        chi_tilde_0_1 = χ_tilde_0_1,
+        chi_tilde_0_2 = χ_tilde_0_2,
+        chi_tilde_plus_1 = χ_tilde_plus_1,
+        chi_tilde_0_3 = χ_tilde_0_3,
+        chi_tilde_0_4 = χ_tilde_0_4,
+        chi_tilde_plus_2 = χ_tilde_plus_2,
+        G_tilde = G_tilde,
+    );
+}
+
+pub mod excited_fermions {
+    use super::*;
+
+    latin_constants!(
+        excited_fermions[CONSTANTS]:
+        d_star = d_star,
+        u_star = u_star,
+        s_star = s_star,
+        c_star = c_star,
+        b_star = b_star,
+        t_star = t_star,
+        e_star = e_star,
+        nu_e_star = ν_e_star,
+        mu_star = μ_star,
+        nu_mu_star = ν_μ_star,
+        tau_star = τ_star,
+        nu_tau_star = ν_τ_star,
+    );
+}
+
+pub mod dark_matter {
+    use super::*;
+
+    latin_constants!(
+        dark_matter[CONSTANTS]:
+        chi_S = χ_S,
+        chi_F = χ_F,
+        chi_V = χ_V,
+        S_med = S_med,
+        V_med = V_med,
+    );
+}
+
+pub mod technicolor {
+    use super::*;
+
+    latin_constants!(
+        technicolor[CONSTANTS]:
+        pi_tc_0 = π_tc_0,
+        rho_tc_0 = ρ_tc_0,
+        pi_tc_plus = π_tc_plus,
+        rho_tc_plus = ρ_tc_plus,
+        pi_prime_tc_0 = π_prime_tc_0,
+        omega_tc = ω_tc,
+        eta_tc = η_tc,
+        V8_tc = V8_tc,
+        pi_22_1_tc = π_22_1_tc,
+        rho_11_tc = ρ_11_tc,
+        pi_22_8_tc = π_22_8_tc,
+        rho_12_tc = ρ_12_tc,
+        rho_21_tc = ρ_21_tc,
+        rho_22_tc = ρ_22_tc,
+    );
+}
+
+#[cfg(feature = "mesons")]
+#[allow(non_snake_case)]
+pub mod light_Ieq1_mesons {
+    use super::*;
+
+    latin_constants!(
+        light_Ieq1_mesons[CONSTANTS]:
+        pi_0 = π_0,
+        pi_plus = π_plus,
+        a_0_980_0 = a_0_980_0,
+        a_0_980_plus = a_0_980_plus,
+        pi_1300_0 = π_1300_0,
+        pi_1300_plus = π_1300_plus,
+        a_0_1450_0 = a_0_1450_0,
+        a_0_1450_plus = a_0_1450_plus,
+        pi_1800_0 = π_1800_0,
+        pi_1800_plus = π_1800_plus,
+        rho_770_0 = ρ_770_0,
+        rho_770_plus = ρ_770_plus,
+        b_1_1235_0 = b_1_1235_0,
+        b_1_1235_plus = b_1_1235_plus,
+        a_1_1260_0 = a_1_1260_0,
+        a_1_1260_plus = a_1_1260_plus,
+        pi_1_1400_0 = π_1_1400_0,
+        pi_1_1400_plus = π_1_1400_plus,
+        rho_1450_0 = ρ_1450_0,
+        rho_1450_plus = ρ_1450_plus,
+        pi_1_1600_0 = π_1_1600_0,
+        pi_1_1600_plus = π_1_1600_plus,
+        a_1_1640_0 = a_1_1640_0,
+        a_1_1640_plus = a_1_1640_plus,
+        rho_1700_0 = ρ_1700_0,
+        rho_1700_plus = ρ_1700_plus,
+        rho_1900_0 = ρ_1900_0,
+        rho_1900_plus = ρ_1900_plus,
+        rho_2150_0 = ρ_2150_0,
+        rho_2150_plus = ρ_2150_plus,
+        a_2_1320_0 = a_2_1320_0,
+        a_2_1320_plus = a_2_1320_plus,
+        pi_2_1670_0 = π_2_1670_0,
+        pi_2_1670_plus = π_2_1670_plus,
+        a_2_1700_0 = a_2_1700_0,
+        a_2_1700_plus = a_2_1700_plus,
+        pi_2_2100_0 = π_2_2100_0,
+        pi_2_2100_plus = π_2_2100_plus,
+        rho_3_1690_0 = ρ_3_1690_0,
+        rho_3_1690_plus = ρ_3_1690_plus,
+        rho_3_1990_0 = ρ_3_1990_0,
+        rho_3_1990_plus = ρ_3_1990_plus,
+        rho_3_2250_0 = ρ_3_2250_0,
+        rho_3_2250_plus = ρ_3_2250_plus,
+        a_4_2040_0 = a_4_2040_0,
+        a_4_2040_plus = a_4_2040_plus,
+    );
+}
+
+#[cfg(feature = "mesons")]
+#[allow(non_snake_case)]
+pub mod light_Ieq0_mesons {
+    use super::*;
+
+    latin_constants!(
+        light_Ieq0_mesons[CONSTANTS]:
+        eta = η,
+        eta_prime_958 = η_prime_958,
+        f_0_500 = f_0_500,
+        f_0_980 = f_0_980,
+        eta_1295 = η_1295,
+        f_0_1370 = f_0_1370,
+        eta_1405 = η_1405,
+        eta_1475 = η_1475,
+        f_0_1500 = f_0_1500,
+        f_0_1710 = f_0_1710,
+        eta_1760 = η_1760,
+        f_0_2020 = f_0_2020,
+        f_0_2100 = f_0_2100,
+        f_0_2200 = f_0_2200,
+        eta_2225 = η_2225,
+        omega_782 = ω_782,
+        phi_1020 = φ_1020,
+        h_1_1170 = h_1_1170,
+        f_1_1285 = f_1_1285,
+        h_1_1380 = h_1_1380,
+        f_1_1420 = f_1_1420,
+        omega_1420 = ω_1420,
+        f_1_1510 = f_1_1510,
+        h_1_1595 = h_1_1595,
+        omega_1650 = ω_1650,
+        phi_1680 = φ_1680,
+        f_2_1270 = f_2_1270,
+        f_2_1430 = f_2_1430,
+        f_2_prime_1525 = f_2_prime_1525,
+        f_2_1565 = f_2_1565,
+        f_2_1640 = f_2_1640,
+        eta_2_1645 = η_2_1645,
+        f_2_1810 = f_2_1810,
+        eta_2_1870 = η_2_1870,
+        f_2_1910 = f_2_1910,
+        f_2_1950 = f_2_1950,
+        f_2_2010 = f_2_2010,
+        f_2_2150 = f_2_2150,
+        f_2_2300 = f_2_2300,
+        f_2_2340 = f_2_2340,
+        omega_3_1670 = ω_3_1670,
+        phi_3_1850 = φ_3_1850,
+        f_4_2050 = f_4_2050,
+        f_J_2220 = f_J_2220,
+        f_4_2300 = f_4_2300,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod light_mesons {
+    pub use super::light_Ieq0_mesons::*;
+    pub use super::light_Ieq1_mesons::*;
+}
+
+#[cfg(feature = "mesons")]
+pub mod strange_mesons {
+    use super::*;
+
+    latin_constants!(
+        strange_mesons[CONSTANTS]:
+        K_0_L = K_0_L,
+        K_0_S = K_0_S,
+        K_0 = K_0,
+        K_plus = K_plus,
+        K_0_star_700_0 = K_0_star_700_0,
+        K_0_star_700_plus = K_0_star_700_plus,
+        K_0_star_1430_0 = K_0_star_1430_0,
+        K_0_star_1430_plus = K_0_star_1430_plus,
+        K_1460_0 = K_1460_0,
+        K_1460_plus = K_1460_plus,
+        K_1830_0 = K_1830_0,
+        K_1830_plus = K_1830_plus,
+        K_0_star_1950_0 = K_0_star_1950_0,
+        K_0_star_1950_plus = K_0_star_1950_plus,
+        K_star_892_0 = K_star_892_0,
+        K_star_892_plus = K_star_892_plus,
+        K_1_1270_0 = K_1_1270_0,
+        K_1_1270_plus = K_1_1270_plus,
+        K_1_1400_0 = K_1_1400_0,
+        K_1_1400_plus = K_1_1400_plus,
+        K_star_1410_0 = K_star_1410_0,
+        K_star_1410_plus = K_star_1410_plus,
+        K_1_1650_0 = K_1_1650_0,
+        K_1_1650_plus = K_1_1650_plus,
+        K_star_1680_0 = K_star_1680_0,
+        K_star_1680_plus = K_star_1680_plus,
+        K_2_star_1430_0 = K_2_star_1430_0,
+        K_2_star_1430_plus = K_2_star_1430_plus,
+        K_2_1580_0 = K_2_1580_0,
+        K_2_1580_plus = K_2_1580_plus,
+        K_2_1770_0 = K_2_1770_0,
+        K_2_1770_plus = K_2_1770_plus,
+        K_2_1820_0 = K_2_1820_0,
+        K_2_1820_plus = K_2_1820_plus,
+        K_2_star_1980_0 = K_2_star_1980_0,
+        K_2_star_1980_plus = K_2_star_1980_plus,
+        K_2_2250_0 = K_2_2250_0,
+        K_2_2250_plus = K_2_2250_plus,
+        K_3_star_1780_0 = K_3_star_1780_0,
+        K_3_star_1780_plus = K_3_star_1780_plus,
+        K_3_2320_0 = K_3_2320_0,
+        K_3_2320_plus = K_3_2320_plus,
+        K_4_star_2045_0 = K_4_star_2045_0,
+        K_4_star_2045_plus = K_4_star_2045_plus,
+        K_4_2500_0 = K_4_2500_0,
+        K_4_2500_plus = K_4_2500_plus,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod charmed_mesons {
+    use super::*;
+
+    latin_constants!(
+        charmed_mesons[CONSTANTS]:
+        D_plus = D_plus,
+        D_0 = D_0,
+        D_0_star_2400_plus = D_0_star_2400_plus,
+        D_0_star_2400_0 = D_0_star_2400_0,
+        D_star_2010_plus = D_star_2010_plus,
+        D_star_2007_0 = D_star_2007_0,
+        D_1_2420_plus = D_1_2420_plus,
+        D_1_2420_0 = D_1_2420_0,
+        D_1_H_plus = D_1_H_plus,
+        D_1_2430_0 = D_1_2430_0,
+        D_2_star_2460_plus = D_2_star_2460_plus,
+        D_2_star_2460_0 = D_2_star_2460_0,
+        D_s_plus = D_s_plus,
+        D_s0_star_2317_plus = D_s0_star_2317_plus,
+        D_s_star_plus = D_s_star_plus,
+        D_s_1_2536_plus = D_s_1_2536_plus,
+        D_s_1_2460_plus = D_s_1_2460_plus,
+        D_s_2_star_2573_plus = D_s_2_star_2573_plus,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod bottom_mesons {
+    use super::*;
+
+    latin_constants!(
+        bottom_mesons[CONSTANTS]:
+        B_0 = B_0,
+        B_plus = B_plus,
+        B_0_star_0 = B_0_star_0,
+        B_0_star_plus = B_0_star_plus,
+        B_star_0 = B_star_0,
+        B_star_plus = B_star_plus,
+        B_1_L_0 = B_1_L_0,
+        B_1_L_plus = B_1_L_plus,
+        B_1_H_0 = B_1_H_0,
+        B_1_H_plus = B_1_H_plus,
+        B_2_star0 = B_2_star0,
+        B_2_star_plus = B_2_star_plus,
+        B_s_0 = B_s_0,
+        B_s_0_star_0 = B_s_0_star_0,
+        B_s_star_0 = B_s_star_0,
+        B_s_1_L_0 = B_s_1_L_0,
+        B_s_1_H_0 = B_s_1_H_0,
+        B_s_2_star_0 = B_s_2_star_0,
+        B_c_plus = B_c_plus,
+        B_c_0_star_plus = B_c_0_star_plus,
+        B_c_star_plus = B_c_star_plus,
+        B_c_1_L_plus = B_c_1_L_plus,
+        B_c_1_H_plus = B_c_1_H_plus,
+        B_c_2_star_plus = B_c_2_star_plus,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod ccbar_mesons {
+    use super::*;
+
+    latin_constants!(
+        ccbar_mesons[CONSTANTS]:
+        eta_c_1S = η_c_1S,
+        chi_c_0_1P = χ_c_0_1P,
+        eta_c_2S = η_c_2S,
+        Jpsi_1S = Jψ_1S,
+        h_c_1P = h_c_1P,
+        chi_c_1_1P = χ_c_1_1P,
+        psi_2S = ψ_2S,
+        psi_3770 = ψ_3770,
+        psi_4040 = ψ_4040,
+        psi_4160 = ψ_4160,
+        psi_4415 = ψ_4415,
+        chi_c_2_1P = χ_c_2_1P,
+        chi_c_2_3930 = χ_c_2_3930,
+        psi_2_3823 = ψ_2_3823,
+        psi_3_3842 = ψ_3_3842,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod bbbar_mesons {
+    use super::*;
+
+    latin_constants!(
+        bbbar_mesons[CONSTANTS]:
+        eta_b_1S = η_b_1S,
+        chi_b_0_1P = χ_b_0_1P,
+        eta_b_2S = η_b_2S,
+        chi_b_0_2P = χ_b_0_2P,
+        eta_b_3S = η_b_3S,
+        chi_b_0_3P = χ_b_0_3P,
+        Upsilon_1S = Υ_1S,
+        h_b_1P = h_b_1P,
+        chi_b_1_1P = χ_b_1_1P,
+        Upsilon_1_1D = Υ_1_1D,
+        Upsilon_2S = Υ_2S,
+        h_b_2P = h_b_2P,
+        chi_b_1_2P = χ_b_1_2P,
+        Upsilon_1_2D = Υ_1_2D,
+        Upsilon_3S = Υ_3S,
+        h_b_3P = h_b_3P,
+        chi_b_1_3P = χ_b_1_3P,
+        Upsilon_4S = Υ_4S,
+        Upsilon_10860 = Υ_10860,
+        Upsilon_11020 = Υ_11020,
+        chi_b_2_1P = χ_b_2_1P,
+        eta_b_2_1D = η_b_2_1D,
+        Upsilon_2_1D = Υ_2_1D,
+        chi_b_2_2P = χ_b_2_2P,
+        eta_b_2_2D = η_b_2_2D,
+        Upsilon_2_2D = Υ_2_2D,
+        chi_b_2_3P = χ_b_2_3P,
+        Upsilon_3_1D = Υ_3_1D,
+        Upsilon_3_2D = Υ_3_2D,
+    );
+}
+
+#[cfg(feature = "mesons")]
+pub mod mesons {
+    pub use super::bbbar_mesons::*;
+    pub use super::bottom_mesons::*;
+    pub use super::ccbar_mesons::*;
+    pub use super::charmed_mesons::*;
+    pub use super::light_mesons::*;
+    pub use super::strange_mesons::*;
+}
+
+#[cfg(feature = "baryons")]
+pub mod light_baryons {
+    use super::*;
+
+    latin_constants!(
+        light_baryons[CONSTANTS, EXTRA_CONSTANTS]:
+        p = p,
+        n = n,
+        Delta_plus_plus = Δ_plus_plus,
+        Delta_plus = Δ_plus,
+        Delta_0 = Δ_0,
+        Delta_minus = Δ_minus,
+        proton = proton,
+        neutron = neutron,
+    );
+}
+
+#[cfg(feature = "baryons")]
+pub mod strange_baryons {
+    use super::*;
+
+    latin_constants!(
+        strange_baryons[CONSTANTS]:
+        Lambda = Λ,
+        Sigma_plus = Σ_plus,
+        Sigma_0 = Σ_0,
+        Sigma_minus = Σ_minus,
+        Sigma_star_plus = Σ_star_plus,
+        Sigma_star_0 = Σ_star_0,
+        Sigma_star_minus = Σ_star_minus,
+        Xi_0 = Ξ_0,
+        Xi_minus = Ξ_minus,
+        Xi_star_0 = Ξ_star_0,
+        Xi_star_minus = Ξ_star_minus,
+        Omega_minus = Ω_minus,
+    );
+}
+
+#[cfg(feature = "baryons")]
+pub mod charmed_baryons {
+    use super::*;
+
+    latin_constants!(
+        charmed_baryons[CONSTANTS]:
+        Lambda_c_plus = Λ_c_plus,
+        Sigma_c_plus_plus = Σ_c_plus_plus,
+        Sigma_c_plus = Σ_c_plus,
+        Sigma_c_0 = Σ_c_0,
+        Sigma_c_star_plus_plus = Σ_c_star_plus_plus,
+        Sigma_c_star_plus = Σ_c_star_plus,
+        Sigma_c_star_0 = Σ_c_star_0,
+        Xi_c_plus = Ξ_c_plus,
+        Xi_c_0 = Ξ_c_0,
+        Xi_c_prime_plus = Ξ_c_prime_plus,
+        Xi_c_prime_0 = Ξ_c_prime_0,
+        Xi_c_star_plus = Ξ_c_star_plus,
+        Xi_c_star_0 = Ξ_c_star_0,
+        Omega_c_0 = Ω_c_0,
+        Omega_c_star_0 = Ω_c_star_0,
+        Xi_c_c_plus = Ξ_c_c_plus,
+        Xi_c_c_plus_plus = Ξ_c_c_plus_plus,
+        Xi_c_c_star_plus = Ξ_c_c_star_plus,
+        Xi_c_c_star_plus_plus = Ξ_c_c_star_plus_plus,
+        Omega_c_c_plus = Ω_c_c_plus,
+        Omega_c_c_star_plus = Ω_c_c_star_plus,
+        Omega_c_c_c_plus_plus = Ω_c_c_c_plus_plus,
+    );
+}
+
+#[cfg(feature = "baryons")]
+pub mod bottom_baryons {
+    use super::*;
+
+    latin_constants!(
+        bottom_baryons[CONSTANTS]:
+        Lambda_b_0 = Λ_b_0,
+        Sigma_b_minus = Σ_b_minus,
+        Sigma_b_0 = Σ_b_0,
+        Sigma_b_plus = Σ_b_plus,
+        Sigma_b_star_minus = Σ_b_star_minus,
+        Sigma_b_star_0 = Σ_b_star_0,
+        Sigma_b_star_plus = Σ_b_star_plus,
+        Xi_b_minus = Ξ_b_minus,
+        Xi_b_0 = Ξ_b_0,
+        Xi_b_prime_minus = Ξ_b_prime_minus,
+        Xi_b_prime_0 = Ξ_b_prime_0,
+        Xi_b_star_minus = Ξ_b_star_minus,
+        Xi_b_star_0 = Ξ_b_star_0,
+        Omega_b_minus = Ω_b_minus,
+        Omega_b_star_minus = Ω_b_star_minus,
+        Xi_b_c_0 = Ξ_b_c_0,
+        Xi_b_c_plus = Ξ_b_c_plus,
+        Xi_b_c_prime_0 = Ξ_b_c_prime_0,
+        Xi_b_c_prime_plus = Ξ_b_c_prime_plus,
+        Xi_b_c_star_0 = Ξ_b_c_star_0,
+        Xi_b_c_star_plus = Ξ_b_c_star_plus,
+        Omega_b_c_0 = Ω_b_c_0,
+        Omega_b_c_prime_0 = Ω_b_c_prime_0,
+        Omega_b_c_star_0 = Ω_b_c_star_0,
+        Omega_b_c_c_plus = Ω_b_c_c_plus,
+        Omega_b_c_c_star_plus = Ω_b_c_c_star_plus,
+        Xi_b_b_minus = Ξ_b_b_minus,
+        Xi_b_b_0 = Ξ_b_b_0,
+        Xi_b_b_star_minus = Ξ_b_b_star_minus,
+        Xi_b_b_star_0 = Ξ_b_b_star_0,
+        Omega_b_b_minus = Ω_b_b_minus,
+        Omega_b_b_star_minus = Ω_b_b_star_minus,
+        Omega_b_b_c_0 = Ω_b_b_c_0,
+        Omega_b_b_c_star_0 = Ω_b_b_c_star_0,
+        Omega_b_b_b_minus = Ω_b_b_b_minus,
+    );
+}
+
+#[cfg(feature = "exotics")]
+pub mod pentaquarks {
+    use super::*;
+
+    latin_constants!(
+        pentaquarks[CONSTANTS]:
+        Theta_plus = Θ_plus,
+        Phi_minus_minus = Φ_minus_minus,
+    );
+}
+
+#[cfg(feature = "baryons")]
+pub mod baryons {
+    pub use super::bottom_baryons::*;
+    pub use super::charmed_baryons::*;
+    pub use super::light_baryons::*;
+    pub use super::strange_baryons::*;
+}
+
+pub mod anti_quarks {
+    use super::*;
+
+    latin_constants!(
+        anti_quarks[CONSTANTS, EXTRA_CONSTANTS]:
+        d_bar = d_bar,
+        u_bar = u_bar,
+        s_bar = s_bar,
+        c_bar = c_bar,
+        b_bar = b_bar,
+        t_bar = t_bar,
+        b_prime_bar = b_prime_bar,
+        t_prime_bar = t_prime_bar,
+        anti_down = anti_down,
+        anti_up = anti_up,
+        anti_strange = anti_strange,
+        anti_charm = anti_charm,
+        anti_bottom = anti_bottom,
+        anti_top = anti_top,
+    );
+}
+
+pub mod anti_leptons {
+    use super::*;
+
+    latin_constants!(
+        anti_leptons[CONSTANTS, EXTRA_CONSTANTS]:
+        e_bar = e_bar,
+        nu_e_bar = ν_e_bar,
+        mu_bar = μ_bar,
+        nu_mu_bar = ν_μ_bar,
+        tau_bar = τ_bar,
+        nu_tau_bar = ν_τ_bar,
+        tau_prime_bar = τ_prime_bar,
+        nu_tau_prime_bar = ν_τ_prime_bar,
+        positron = positron,
+        electron_anti_neutrino = electron_anti_neutrino,
+        mu_plus = mu_plus,
+        anti_muon = anti_muon,
+        muon_anti_neutrino = muon_anti_neutrino,
+        tau_plus = tau_plus,
+        anti_tau = anti_tau,
+        tau_anti_neutrino = tau_anti_neutrino,
+        tau_prime_anti_neutrino = tau_prime_anti_neutrino,
+    );
+}
+
+pub mod anti_gauge_and_higgs_bosons {
+    use super::*;
+
+    latin_constants!(
+        anti_gauge_and_higgs_bosons[EXTRA_CONSTANTS]:
+        W_minus = W_minus,
+        W_prime_minus = W_prime_minus,
+        H_minus = H_minus,
+        H_minus_minus = H_minus_minus,
+    );
+}
+
+#[cfg(feature = "baryons")]
+pub mod light_anti_baryons {
+    use super::*;
+
+    latin_constants!(
+        light_anti_baryons[EXTRA_CONSTANTS]:
+        p_bar = p_bar,
+        anti_proton = anti_proton,
+        n_bar = n_bar,
+        anti_neutron = anti_neutron,
+    );
+}
+
+#[cfg(feature = "susy")]
+pub mod susy_anti_particles {
+    use super::*;
+
+    latin_constants!(
+        susy_anti_particles[EXTRA_CONSTANTS]:
+        d_tilde_bar_L = d_tilde_bar_L,
+        u_tilde_bar_L = u_tilde_bar_L,
+        s_tilde_bar_L = s_tilde_bar_L,
+        c_tilde_bar_L = c_tilde_bar_L,
+        b_tilde_bar_1 = b_tilde_bar_1,
+        t_tilde_bar_1 = t_tilde_bar_1,
+        e_tilde_bar_L = e_tilde_bar_L,
+        nu_e_tilde_bar_L = ν_e_tilde_bar_L,
+        mu_tilde_bar_L = μ_tilde_bar_L,
+        nu_mu_tilde_bar_L = ν_μ_tilde_bar_L,
+        tau_tilde_bar_1 = τ_tilde_bar_1,
+        nu_tau_tilde_bar_L = ν_τ_tilde_bar_L,
+        d_tilde_bar_R = d_tilde_bar_R,
+        u_tilde_bar_R = u_tilde_bar_R,
+        s_tilde_bar_R = s_tilde_bar_R,
+        c_tilde_bar_R = c_tilde_bar_R,
+        b_tilde_bar_2 = b_tilde_bar_2,
+        t_tilde_bar_2 = t_tilde_bar_2,
+        e_tilde_bar_R = e_tilde_bar_R,
+        mu_tilde_bar_R = μ_tilde_bar_R,
+        tau_tilde_bar_2 = τ_tilde_bar_2,
+        chi_tilde_minus_1 = χ_tilde_minus_1,
+        chi_tilde_minus_2 = χ_tilde_minus_2,
+    );
+}
+
+pub mod sm_elementary_particles {
+    pub use super::anti_gauge_and_higgs_bosons::*;
+    pub use super::anti_leptons::*;
+    pub use super::anti_quarks::*;
+    pub use super::gauge_and_higgs_bosons::*;
+    pub use super::leptons::*;
+    pub use super::quarks::*;
+}
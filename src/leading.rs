@@ -0,0 +1,146 @@
+//! Leading particles in weighted collections
+//!
+//! The functions in this module find the particles with the largest
+//! weight, e.g. the largest transverse momentum, among
+//! `(ParticleID, weight)` pairs. Ties are resolved in favor of the
+//! particle that comes first. Weights that are not comparable to
+//! themselves, like NaN, are ignored.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::leading::*;
+//! use particle_id::ParticleClass;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! // particles with their transverse momenta
+//! let event = [(electron, 25.), (muon, 40.), (photon, 60.), (electron, 32.)];
+//! assert_eq!(leading(event), Some((photon, 60.)));
+//!
+//! let leaders = leading_by_species(event);
+//! assert_eq!(leaders.get(electron), Some(&32.));
+//! assert_eq!(leaders.get(positron), None);
+//!
+//! let classes = [ParticleClass::ChargedLepton, ParticleClass::Quark];
+//! assert_eq!(leading_by_class(event, &classes), [Some((muon, 40.)), None]);
+//! ```
+use crate::{ParticleClass, ParticleID, ParticleMap};
+
+/// Particle with the largest weight
+///
+/// Returns `None` if there are no particles with comparable weights.
+pub fn leading<I, W>(particles: I) -> Option<(ParticleID, W)>
+where
+    I: IntoIterator<Item = (ParticleID, W)>,
+    W: PartialOrd,
+{
+    leading_by(particles, |_| true)
+}
+
+/// Particle with the largest weight among those for which `selector`
+/// returns `true`
+///
+/// # Example
+///
+/// ```
+/// use particle_id::leading::leading_by;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let event = [(u, 80.), (nu_e, 35.), (anti_muon, 30.)];
+/// let leading_lepton = leading_by(event, |particle| particle.abs().is_lepton());
+/// assert_eq!(leading_lepton, Some((nu_e, 35.)));
+/// ```
+pub fn leading_by<I, W, F>(
+    particles: I,
+    mut selector: F,
+) -> Option<(ParticleID, W)>
+where
+    I: IntoIterator<Item = (ParticleID, W)>,
+    W: PartialOrd,
+    F: FnMut(ParticleID) -> bool,
+{
+    let mut leader: Option<(ParticleID, W)> = None;
+    for (particle, weight) in particles {
+        if !is_comparable(&weight) || !selector(particle) {
+            continue;
+        }
+        match &leader {
+            Some((_, max)) if weight <= *max => {}
+            _ => leader = Some((particle, weight)),
+        }
+    }
+    leader
+}
+
+/// Particle with the largest weight in the given class
+///
+/// See [ParticleClass::contains].
+pub fn leading_in_class<I, W>(
+    particles: I,
+    class: ParticleClass,
+) -> Option<(ParticleID, W)>
+where
+    I: IntoIterator<Item = (ParticleID, W)>,
+    W: PartialOrd,
+{
+    leading_by(particles, |particle| class.contains(particle))
+}
+
+/// Largest weight for each particle species
+///
+/// Particles and anti-particles are treated as different species.
+pub fn leading_by_species<I, W>(particles: I) -> ParticleMap<W>
+where
+    I: IntoIterator<Item = (ParticleID, W)>,
+    W: PartialOrd,
+{
+    let mut leaders = ParticleMap::new();
+    for (particle, weight) in particles {
+        if !is_comparable(&weight) {
+            continue;
+        }
+        match leaders.get_mut(particle) {
+            Some(max) if weight > *max => *max = weight,
+            Some(_) => {}
+            None => {
+                leaders.insert(particle, weight);
+            }
+        }
+    }
+    leaders
+}
+
+/// Particle with the largest weight in each of the given classes
+///
+/// The result contains one entry for each class, in the same order.
+/// Since classes can overlap, a particle can be the leader of
+/// several classes.
+pub fn leading_by_class<I, W>(
+    particles: I,
+    classes: &[ParticleClass],
+) -> Vec<Option<(ParticleID, W)>>
+where
+    I: IntoIterator<Item = (ParticleID, W)>,
+    W: PartialOrd + Clone,
+{
+    let mut leaders: Vec<Option<(ParticleID, W)>> = vec![None; classes.len()];
+    for (particle, weight) in particles {
+        if !is_comparable(&weight) {
+            continue;
+        }
+        for (leader, class) in leaders.iter_mut().zip(classes) {
+            if !class.contains(particle) {
+                continue;
+            }
+            match leader {
+                Some((_, max)) if weight <= *max => {}
+                _ => *leader = Some((particle, weight.clone())),
+            }
+        }
+    }
+    leaders
+}
+
+fn is_comparable<W: PartialOrd>(weight: &W) -> bool {
+    weight.partial_cmp(weight).is_some()
+}
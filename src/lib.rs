@@ -4,13 +4,116 @@
 //! # Example
 //!
 //! ```
+//! # #[cfg(all(feature = "baryons", feature = "full-names"))] {
 //! use particle_id::light_baryons::*;
 //!
 //! assert_eq!(proton.name().unwrap(), "proton");
 //! assert_eq!(proton.id(), 2212);
 //! assert_eq!(proton.anti().id(), -proton.id());
+//! # }
 //! ```
+//!
+//! # Features
+//!
+//! All of the following features are enabled by default. Disabling
+//! them reduces the size of the embedded particle tables.
+//!
+//! - `mesons`: Meson constants.
+//! - `baryons`: Baryon constants.
+//! - `susy`: Constants for supersymmetric particles.
+//! - `exotics`: Constants for exotic hadrons, e.g. pentaquarks.
+//! - `full-names`: Names and symbols for hadrons and exotic particles.
+//!   Without this feature, only elementary particles have names.
+//!
+//! The following features are optional:
+//!
+//! - `decays`: Branching fractions for common decay channels.
+//! - `masses`: Masses and widths of common particles, magnitudes of
+//!   CKM matrix elements, and couplings to the Z boson.
+//! - `uom`: Masses, widths, and lifetimes as [uom](https://docs.rs/uom)
+//!   quantities. Implies `masses`.
+//! - `rand`: Generation of random particle codes with
+//!   [rand](https://docs.rs/rand).
+//! - `cpp`: Generation of C++ headers with particle constants.
 #![allow(non_upper_case_globals)]
+pub mod anomaly;
+pub mod batch;
+mod beam;
+mod calo;
+mod charge;
+pub mod ckm;
+mod class;
+mod content;
+pub mod count;
+mod counter;
+#[cfg(feature = "cpp")]
+pub mod cpp;
+#[cfg(feature = "decays")]
+mod decays;
+mod electroweak;
+mod error;
+mod flavor;
+pub mod flow;
+pub mod fortran;
+mod fragmentation;
+mod index;
+pub mod jet;
+pub mod latin;
+pub mod leading;
+mod map;
+#[cfg(feature = "masses")]
+mod masses;
+mod metadata;
+pub mod multiparticle;
+mod names;
+mod parity;
+mod pdf;
+mod polarization;
+#[cfg(feature = "uom")]
+mod quantities;
+mod quantum_numbers;
+#[cfg(feature = "rand")]
+mod random;
+mod rivet;
+mod set;
+mod spin;
+pub mod status;
+mod susy;
+#[cfg(feature = "mesons")]
+pub mod tau;
+pub mod test_support;
+pub mod token;
+mod truth;
+mod units;
+pub mod vertex;
+
+pub use beam::BeamKind;
+pub use calo::CaloCategory;
+pub use charge::Charge;
+pub use class::ParticleClass;
+pub use content::QuarkContent;
+pub use counter::ParticleCounter;
+#[cfg(feature = "masses")]
+pub use electroweak::ZCouplings;
+pub use error::Error;
+pub use flavor::{FlavorNumbers, LeptonFlavor};
+pub use fragmentation::FragmentationClass;
+pub use map::ParticleMap;
+#[cfg(feature = "masses")]
+pub use masses::Measurement;
+pub use metadata::MetadataStore;
+pub use names::NameStyle;
+pub use parity::Parity;
+pub use polarization::Polarization;
+pub use quantum_numbers::QuantumNumbers;
+pub use set::ParticleSet;
+pub use spin::Spin;
+pub use status::StatusCode;
+pub use susy::SfermionIndex;
+pub use units::{EnergyUnit, LengthUnit};
+
+use std::str::FromStr;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +124,24 @@ use serde::{Deserialize, Serialize};
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct ParticleID(i32);
 
+/// Sort particles by absolute value, with particles before anti-particles
+///
+/// See [ParticleID::cmp_by_abs].
+///
+/// # Example
+///
+/// ```
+/// use particle_id::sort_by_abs;
+/// use particle_id::sm_elementary_particles::*;
+/// let mut particles = [nu_e, positron, photon, electron];
+/// sort_by_abs(&mut particles);
+/// assert_eq!(particles, [electron, positron, nu_e, photon]);
+/// ```
+pub fn sort_by_abs(particles: &mut [ParticleID]) {
+    particles.sort_unstable_by(ParticleID::cmp_by_abs)
+}
+
+#[cfg(any(feature = "mesons", feature = "baryons"))]
 const fn concat<const A: usize, const B: usize, const C: usize>(
     a: [ParticleID; A],
     b: [ParticleID; B],
@@ -45,6 +166,7 @@ const fn concat<const A: usize, const B: usize, const C: usize>(
     result
 }
 
+#[cfg(any(feature = "mesons", feature = "baryons"))]
 macro_rules! concat_arrays {
     ($($arr:expr),*) => {
         concat_arrays!(@concat $( [$arr ; $arr.len()] )*)
@@ -76,6 +198,20 @@ macro_rules! particle_set {
             pub const $set: [ParticleID;  count!($($particle)*)] = [
                 $($particle,)*
             ];
+            pub(crate) const CONSTANTS: [(&str, ParticleID); count!($($particle)*)] = [
+                $((stringify!($particle), $particle),)*
+            ];
+    };
+}
+
+macro_rules! particle_constants {
+    ($($name:ident = $value:expr,)*) => {
+        $(
+            pub const $name: ParticleID = $value;
+        )*
+            pub(crate) const EXTRA_CONSTANTS: [(&str, ParticleID); count!($($name)*)] = [
+                $((stringify!($name), $name),)*
+            ];
     };
 }
 
@@ -94,12 +230,14 @@ pub mod quarks {
         }
     );
 
-    pub const down: ParticleID = d;
-    pub const up: ParticleID = u;
-    pub const strange: ParticleID = s;
-    pub const charm: ParticleID = c;
-    pub const bottom: ParticleID = b;
-    pub const top: ParticleID = t;
+    particle_constants!(
+        down = d,
+        up = u,
+        strange = s,
+        charm = c,
+        bottom = b,
+        top = t,
+    );
 }
 
 pub mod leptons {
@@ -117,19 +255,21 @@ pub mod leptons {
         }
     );
 
-    pub const electron: ParticleID = e;
-    pub const nu_e: ParticleID = ν_e;
-    pub const electron_neutrino: ParticleID = ν_e;
-    pub const mu: ParticleID = μ;
-    pub const muon: ParticleID = μ;
-    pub const nu_mu: ParticleID = ν_μ;
-    pub const muon_neutrino: ParticleID = ν_μ;
-    pub const tau: ParticleID = τ;
-    pub const nu_tau: ParticleID = ν_τ;
-    pub const tau_neutrino: ParticleID = ν_τ;
-    pub const tau_prime: ParticleID = τ_prime;
-    pub const nu_tau_prime: ParticleID = ν_τ_prime;
-    pub const tau_prime_neutrino: ParticleID = ν_τ_prime;
+    particle_constants!(
+        electron = e,
+        nu_e = ν_e,
+        electron_neutrino = ν_e,
+        mu = μ,
+        muon = μ,
+        nu_mu = ν_μ,
+        muon_neutrino = ν_μ,
+        tau = τ,
+        nu_tau = ν_τ,
+        tau_neutrino = ν_τ,
+        tau_prime = τ_prime,
+        nu_tau_prime = ν_τ_prime,
+        tau_prime_neutrino = ν_τ_prime,
+    );
 }
 
 pub mod gauge_bosons {
@@ -145,9 +285,7 @@ pub mod gauge_bosons {
             W_prime: 34,
         }
     );
-    pub const gluon: ParticleID = g;
-    pub const gamma: ParticleID = γ;
-    pub const photon: ParticleID = γ;
+    particle_constants!(gluon = g, gamma = γ, photon = γ,);
 }
 
 pub mod higgs_bosons {
@@ -162,11 +300,7 @@ pub mod higgs_bosons {
             a0: 40,
         }
     );
-    pub const H: ParticleID = h;
-    pub const Higgs: ParticleID = h;
-    pub const H_0: ParticleID = H0;
-    pub const A_0: ParticleID = A0;
-    pub const a_0: ParticleID = a0;
+    particle_constants!(H = h, Higgs = h, H_0 = H0, A_0 = A0, a_0 = a0,);
 }
 
 pub mod gauge_and_higgs_bosons {
@@ -186,7 +320,7 @@ pub mod special_particles {
             odderon: 9990,
         }
     );
-    pub const graviton: ParticleID = G;
+    particle_constants!(graviton = G,);
 }
 
 pub mod diquarks {
@@ -222,6 +356,7 @@ pub mod diquarks {
     );
 }
 
+#[cfg(feature = "susy")]
 pub mod susy_particles {
     use super::*;
     particle_set!(
@@ -259,6 +394,62 @@ pub mod susy_particles {
     );
 }
 
+pub mod excited_fermions {
+    use super::*;
+    particle_set!(
+        EXCITED_FERMIONS = {
+            d_star: 4000001,
+            u_star: 4000002,
+            s_star: 4000003,
+            c_star: 4000004,
+            b_star: 4000005,
+            t_star: 4000006,
+            e_star: 4000011,
+            ν_e_star: 4000012,
+            μ_star: 4000013,
+            ν_μ_star: 4000014,
+            τ_star: 4000015,
+            ν_τ_star: 4000016,
+        }
+    );
+}
+
+pub mod dark_matter {
+    use super::*;
+    particle_set!(
+        DARK_MATTER = {
+            χ_S: 51,
+            χ_F: 52,
+            χ_V: 53,
+            S_med: 54,
+            V_med: 55,
+        }
+    );
+}
+
+pub mod technicolor {
+    use super::*;
+    particle_set!(
+        TECHNICOLOR = {
+            π_tc_0: 3000111,
+            ρ_tc_0: 3000113,
+            π_tc_plus: 3000211,
+            ρ_tc_plus: 3000213,
+            π_prime_tc_0: 3000221,
+            ω_tc: 3000223,
+            η_tc: 3000331,
+            V8_tc: 3100021,
+            π_22_1_tc: 3100111,
+            ρ_11_tc: 3100113,
+            π_22_8_tc: 3200111,
+            ρ_12_tc: 3200113,
+            ρ_21_tc: 3300113,
+            ρ_22_tc: 3400113,
+        }
+    );
+}
+
+#[cfg(feature = "mesons")]
 #[allow(non_snake_case)]
 pub mod light_Ieq1_mesons {
     use super::*;
@@ -312,8 +503,12 @@ pub mod light_Ieq1_mesons {
             a_4_2040_plus: 219,
         }
     );
+
+    /// Ground-state pions
+    pub const PIONS: [ParticleID; 3] = [π_plus, π_plus.anti(), π_0];
 }
 
+#[cfg(feature = "mesons")]
 #[allow(non_snake_case)]
 pub mod light_Ieq0_mesons {
     use super::*;
@@ -368,6 +563,7 @@ pub mod light_Ieq0_mesons {
     );
 }
 
+#[cfg(feature = "mesons")]
 pub mod light_mesons {
     use crate::ParticleID;
 
@@ -377,6 +573,7 @@ pub mod light_mesons {
         concat_arrays!(LIGHT_IEQ0_MESONS, LIGHT_IEQ1_MESONS);
 }
 
+#[cfg(feature = "mesons")]
 pub mod strange_mesons {
     use super::*;
     particle_set!(
@@ -429,8 +626,14 @@ pub mod strange_mesons {
             K_4_2500_plus: 9000329,
         }
     );
+
+    /// Ground-state kaons and anti-kaons, including both the flavor
+    /// eigenstates K⁰, K̄⁰ and the mass eigenstates K⁰_L, K⁰_S
+    pub const KAONS: [ParticleID; 6] =
+        [K_plus, K_plus.anti(), K_0, K_0.anti(), K_0_L, K_0_S];
 }
 
+#[cfg(feature = "mesons")]
 pub mod charmed_mesons {
     use super::*;
 
@@ -458,6 +661,7 @@ pub mod charmed_mesons {
     );
 }
 
+#[cfg(feature = "mesons")]
 pub mod bottom_mesons {
     use super::*;
     particle_set!(
@@ -490,6 +694,7 @@ pub mod bottom_mesons {
     );
 }
 
+#[cfg(feature = "mesons")]
 pub mod ccbar_mesons {
     use super::*;
     particle_set!(
@@ -507,10 +712,13 @@ pub mod ccbar_mesons {
             ψ_4415: 9020443,
             χ_c_2_1P: 445,
             χ_c_2_3930: 100445,
+            ψ_2_3823: 20445,
+            ψ_3_3842: 447,
         }
     );
 }
 
+#[cfg(feature = "mesons")]
 pub mod bbbar_mesons {
     use super::*;
     particle_set!(
@@ -548,6 +756,7 @@ pub mod bbbar_mesons {
     );
 }
 
+#[cfg(feature = "mesons")]
 pub mod mesons {
     use crate::ParticleID;
 
@@ -557,7 +766,7 @@ pub mod mesons {
     pub use super::charmed_mesons::*;
     pub use super::light_mesons::*;
     pub use super::strange_mesons::*;
-    pub const MESONS: [ParticleID; 221] = concat_arrays!(
+    pub const MESONS: [ParticleID; 223] = concat_arrays!(
         LIGHT_MESONS,
         STRANGE_MESONS,
         CHARMED_MESONS,
@@ -565,8 +774,44 @@ pub mod mesons {
         CCBAR_MESONS,
         BBBAR_MESONS
     );
+
+    /// All catalogued mesons and anti-mesons with the given
+    /// constituent quark and anti-quark
+    ///
+    /// The order of the arguments does not matter. The mass
+    /// eigenstates K⁰_L and K⁰_S are never included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::mesons::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let b_s = states_with_content(s, b_bar);
+    /// assert!(b_s.contains(&B_s_0));
+    /// assert!(b_s.contains(&B_s_star_0));
+    /// assert!(!b_s.contains(&B_s_0.anti()));
+    /// ```
+    pub fn states_with_content(
+        q1: ParticleID,
+        q2: ParticleID,
+    ) -> Vec<ParticleID> {
+        let mut content = [q1, q2];
+        content.sort();
+        MESONS
+            .into_iter()
+            .flat_map(|m| [m, m.anti()])
+            .filter(|m| match m.meson_quarks() {
+                Some(mut q) => {
+                    q.sort();
+                    q == content
+                }
+                None => false,
+            })
+            .collect()
+    }
 }
 
+#[cfg(feature = "baryons")]
 pub mod light_baryons {
     use super::*;
 
@@ -580,10 +825,10 @@ pub mod light_baryons {
             Δ_minus: 1114,
         }
     );
-    pub const proton: ParticleID = p;
-    pub const neutron: ParticleID = n;
+    particle_constants!(proton = p, neutron = n,);
 }
 
+#[cfg(feature = "baryons")]
 pub mod strange_baryons {
     use super::*;
     particle_set!(
@@ -602,8 +847,66 @@ pub mod strange_baryons {
             Ω_minus: 3334,
         }
     );
+
+    /// Λ baryons and anti-baryons
+    pub const LAMBDAS: [ParticleID; 2] = [Λ, Λ.anti()];
+    /// Σ and Σ* baryons and anti-baryons
+    pub const SIGMAS: [ParticleID; 12] = [
+        Σ_plus,
+        Σ_plus.anti(),
+        Σ_0,
+        Σ_0.anti(),
+        Σ_minus,
+        Σ_minus.anti(),
+        Σ_star_plus,
+        Σ_star_plus.anti(),
+        Σ_star_0,
+        Σ_star_0.anti(),
+        Σ_star_minus,
+        Σ_star_minus.anti(),
+    ];
+    /// Ξ and Ξ* baryons and anti-baryons
+    pub const XIS: [ParticleID; 8] = [
+        Ξ_0,
+        Ξ_0.anti(),
+        Ξ_minus,
+        Ξ_minus.anti(),
+        Ξ_star_0,
+        Ξ_star_0.anti(),
+        Ξ_star_minus,
+        Ξ_star_minus.anti(),
+    ];
+    /// Ω baryons and anti-baryons
+    pub const OMEGAS: [ParticleID; 2] = [Ω_minus, Ω_minus.anti()];
+    /// Strange baryons and anti-baryons
+    pub const HYPERONS: [ParticleID; 24] =
+        concat_arrays!(LAMBDAS, SIGMAS, XIS, OMEGAS);
+
+    /// Final-state hadrons and photons from hyperon decays
+    ///
+    /// Hyperons appearing in decay chains, like the Λ in Ξ⁻ → Λ π⁻,
+    /// are not included.
+    #[cfg(feature = "mesons")]
+    pub const HYPERON_DECAY_PRODUCTS: [ParticleID; 10] = {
+        use super::light_baryons::{n, p};
+        use super::light_mesons::{π_0, π_plus};
+        use super::strange_mesons::K_plus;
+        [
+            p,
+            p.anti(),
+            n,
+            n.anti(),
+            π_plus,
+            π_plus.anti(),
+            π_0,
+            K_plus,
+            K_plus.anti(),
+            gauge_bosons::γ,
+        ]
+    };
 }
 
+#[cfg(feature = "baryons")]
 pub mod charmed_baryons {
     use super::*;
     particle_set!(
@@ -634,6 +937,7 @@ pub mod charmed_baryons {
     );
 }
 
+#[cfg(feature = "baryons")]
 pub mod bottom_baryons {
     use super::*;
     particle_set!(
@@ -677,6 +981,7 @@ pub mod bottom_baryons {
     );
 }
 
+#[cfg(feature = "exotics")]
 pub mod pentaquarks {
     use super::*;
     particle_set!(
@@ -687,6 +992,7 @@ pub mod pentaquarks {
     );
 }
 
+#[cfg(feature = "baryons")]
 pub mod baryons {
     use crate::ParticleID;
 
@@ -700,14 +1006,48 @@ pub mod baryons {
         CHARMED_BARYONS,
         BOTTOM_BARYONS
     );
+
+    /// All catalogued baryons and anti-baryons with the given
+    /// constituent quarks
+    ///
+    /// The order of the arguments does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::baryons::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(states_with_content3(s, s, s), [Ω_minus]);
+    /// assert_eq!(states_with_content3(u_bar, d_bar, u_bar), [p.anti(), Δ_plus.anti()]);
+    /// ```
+    pub fn states_with_content3(
+        q1: ParticleID,
+        q2: ParticleID,
+        q3: ParticleID,
+    ) -> Vec<ParticleID> {
+        let mut content = [q1, q2, q3];
+        content.sort();
+        BARYONS
+            .into_iter()
+            .flat_map(|b| [b, b.anti()])
+            .filter(|b| match b.baryon_quarks() {
+                Some(mut q) => {
+                    q.sort();
+                    q == content
+                }
+                None => false,
+            })
+            .collect()
+    }
 }
 
+#[cfg(all(feature = "mesons", feature = "baryons"))]
 pub mod hadrons {
     use crate::ParticleID;
 
     pub use super::baryons::BARYONS;
     pub use super::mesons::MESONS;
-    pub const HADRONS: [ParticleID; 296] = concat_arrays!(MESONS, BARYONS);
+    pub const HADRONS: [ParticleID; 298] = concat_arrays!(MESONS, BARYONS);
 }
 
 pub mod anti_quarks {
@@ -724,12 +1064,14 @@ pub mod anti_quarks {
             t_prime_bar: -9,
         }
     );
-    pub const anti_down: ParticleID = d_bar;
-    pub const anti_up: ParticleID = u_bar;
-    pub const anti_strange: ParticleID = s_bar;
-    pub const anti_charm: ParticleID = c_bar;
-    pub const anti_bottom: ParticleID = b_bar;
-    pub const anti_top: ParticleID = t_bar;
+    particle_constants!(
+        anti_down = d_bar,
+        anti_up = u_bar,
+        anti_strange = s_bar,
+        anti_charm = c_bar,
+        anti_bottom = b_bar,
+        anti_top = t_bar,
+    );
 }
 
 pub mod anti_leptons {
@@ -747,72 +1089,82 @@ pub mod anti_leptons {
             ν_τ_prime_bar: -18,
         }
     );
-    pub const positron: ParticleID = e_bar;
-    pub const nu_e_bar: ParticleID = ν_e_bar;
-    pub const electron_anti_neutrino: ParticleID = ν_e_bar;
-    pub const mu_bar: ParticleID = μ_bar;
-    pub const mu_plus: ParticleID = μ_bar;
-    pub const μ_plus: ParticleID = μ_bar;
-    pub const anti_muon: ParticleID = μ_bar;
-    pub const nu_mu_bar: ParticleID = ν_μ_bar;
-    pub const muon_anti_neutrino: ParticleID = ν_μ_bar;
-    pub const tau_bar: ParticleID = τ_bar;
-    pub const tau_plus: ParticleID = τ_bar;
-    pub const τ_plus: ParticleID = τ_bar;
-    pub const anti_tau: ParticleID = τ_bar;
-    pub const nu_tau_bar: ParticleID = ν_τ_bar;
-    pub const tau_anti_neutrino: ParticleID = ν_τ_bar;
-    pub const tau_prime_bar: ParticleID = τ_prime_bar;
-    pub const nu_tau_prime_bar: ParticleID = ν_τ_prime_bar;
-    pub const tau_prime_anti_neutrino: ParticleID = ν_τ_prime_bar;
+    particle_constants!(
+        positron = e_bar,
+        nu_e_bar = ν_e_bar,
+        electron_anti_neutrino = ν_e_bar,
+        mu_bar = μ_bar,
+        mu_plus = μ_bar,
+        μ_plus = μ_bar,
+        anti_muon = μ_bar,
+        nu_mu_bar = ν_μ_bar,
+        muon_anti_neutrino = ν_μ_bar,
+        tau_bar = τ_bar,
+        tau_plus = τ_bar,
+        τ_plus = τ_bar,
+        anti_tau = τ_bar,
+        nu_tau_bar = ν_τ_bar,
+        tau_anti_neutrino = ν_τ_bar,
+        tau_prime_bar = τ_prime_bar,
+        nu_tau_prime_bar = ν_τ_prime_bar,
+        tau_prime_anti_neutrino = ν_τ_prime_bar,
+    );
 }
 
 pub mod anti_gauge_and_higgs_bosons {
     use super::*;
 
-    pub const W_minus: ParticleID = ParticleID(-24);
-    pub const W_prime_minus: ParticleID = ParticleID(-34);
-    pub const H_minus: ParticleID = ParticleID(-37);
-    pub const H_minus_minus: ParticleID = ParticleID(-38);
+    particle_constants!(
+        W_minus = ParticleID(-24),
+        W_prime_minus = ParticleID(-34),
+        H_minus = ParticleID(-37),
+        H_minus_minus = ParticleID(-38),
+    );
 }
 
+#[cfg(feature = "baryons")]
 pub mod light_anti_baryons {
     use super::{
         light_baryons::{n, p},
         ParticleID,
     };
-    pub const p_bar: ParticleID = p.anti();
-    pub const anti_proton: ParticleID = p_bar;
-    pub const n_bar: ParticleID = n.anti();
-    pub const anti_neutron: ParticleID = n_bar;
+    particle_constants!(
+        p_bar = p.anti(),
+        anti_proton = p_bar,
+        n_bar = n.anti(),
+        anti_neutron = n_bar,
+    );
 }
 
+#[cfg(feature = "susy")]
 pub mod susy_anti_particles {
     use super::*;
 
-    pub const d_tilde_bar_L: ParticleID = ParticleID(-1000001);
-    pub const u_tilde_bar_L: ParticleID = ParticleID(-1000002);
-    pub const s_tilde_bar_L: ParticleID = ParticleID(-1000003);
-    pub const c_tilde_bar_L: ParticleID = ParticleID(-1000004);
-    pub const b_tilde_bar_1: ParticleID = ParticleID(-1000005);
-    pub const t_tilde_bar_1: ParticleID = ParticleID(-1000006);
-    pub const e_tilde_bar_L: ParticleID = ParticleID(-1000011);
-    pub const ν_e_tilde_bar_L: ParticleID = ParticleID(-1000012);
-    pub const μ_tilde_bar_L: ParticleID = ParticleID(-1000013);
-    pub const ν_μ_tilde_bar_L: ParticleID = ParticleID(-1000014);
-    pub const τ_tilde_bar_1: ParticleID = ParticleID(-1000015);
-    pub const ν_τ_tilde_bar_L: ParticleID = ParticleID(-1000016);
-    pub const d_tilde_bar_R: ParticleID = ParticleID(-2000001);
-    pub const u_tilde_bar_R: ParticleID = ParticleID(-2000002);
-    pub const s_tilde_bar_R: ParticleID = ParticleID(-2000003);
-    pub const c_tilde_bar_R: ParticleID = ParticleID(-2000004);
-    pub const b_tilde_bar_2: ParticleID = ParticleID(-2000005);
-    pub const t_tilde_bar_2: ParticleID = ParticleID(-2000006);
-    pub const e_tilde_bar_R: ParticleID = ParticleID(-2000011);
-    pub const μ_tilde_bar_R: ParticleID = ParticleID(-2000013);
-    pub const τ_tilde_bar_2: ParticleID = ParticleID(-2000015);
-    pub const χ_tilde_minus_1: ParticleID = ParticleID(-1000024);
-    pub const χ_tilde_minus_2: ParticleID = ParticleID(-1000037);
+    particle_constants!(
+        d_tilde_bar_L = ParticleID(-1000001),
+        u_tilde_bar_L = ParticleID(-1000002),
+        s_tilde_bar_L = ParticleID(-1000003),
+        c_tilde_bar_L = ParticleID(-1000004),
+        b_tilde_bar_1 = ParticleID(-1000005),
+        t_tilde_bar_1 = ParticleID(-1000006),
+        e_tilde_bar_L = ParticleID(-1000011),
+        ν_e_tilde_bar_L = ParticleID(-1000012),
+        μ_tilde_bar_L = ParticleID(-1000013),
+        ν_μ_tilde_bar_L = ParticleID(-1000014),
+        τ_tilde_bar_1 = ParticleID(-1000015),
+        ν_τ_tilde_bar_L = ParticleID(-1000016),
+        d_tilde_bar_R = ParticleID(-2000001),
+        u_tilde_bar_R = ParticleID(-2000002),
+        s_tilde_bar_R = ParticleID(-2000003),
+        c_tilde_bar_R = ParticleID(-2000004),
+        b_tilde_bar_2 = ParticleID(-2000005),
+        t_tilde_bar_2 = ParticleID(-2000006),
+        e_tilde_bar_R = ParticleID(-2000011),
+        μ_tilde_bar_R = ParticleID(-2000013),
+        τ_tilde_bar_2 = ParticleID(-2000015),
+        χ_tilde_minus_1 = ParticleID(-1000024),
+        χ_tilde_minus_2 = ParticleID(-1000037),
+    );
 }
 
 pub mod sm_elementary_particles {
@@ -833,160 +1185,2263 @@ impl ParticleID {
     /// Particle symbol in LaTeX format
     pub const fn latex_symbol(&self) -> Option<&'static str> {
         // TODO: antiparticles?
-        use light_anti_baryons::*;
-        use light_baryons::*;
-        use sm_elementary_particles::*;
+        if let Some(name) = self.elementary_latex_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "mesons", feature = "full-names"))]
+        if let Some(name) = self.meson_latex_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "baryons", feature = "full-names"))]
+        if let Some(name) = self.baryon_latex_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "exotics", feature = "full-names"))]
+        if let Some(name) = self.exotic_latex_symbol() {
+            return Some(name);
+        }
+        None
+    }
 
-        let name = match *self {
-            d => "d",
-            u => "u",
-            s => "s",
-            c => "c",
-            b => "b",
-            t => "t",
-            b_prime => "b'",
-            t_prime => "t'",
-            e => "e^-",
-            ν_e => r"\nu_e",
-            μ => r"\mu^-",
-            ν_μ => r"\nu_\mu",
-            τ => r"\tau^-",
-            ν_τ => r"\nu_\tau",
-            τ_prime => r"\tau'^-",
-            ν_τ_prime => r"\nu_{\tau'}",
-            g => "g",
-            γ => r"\gamma",
-            Z => r"Z",
-            W_plus => r"W^+",
-            h => "h",
-            Z_prime => "Z'",
-            Z_prime_prime => "Z''",
-            W_prime => "W'",
-            H0 => "H^0",
-            A0 => "A^0",
-            H_plus => "H^+",
-            H_plus_plus => "H^{++}",
-            a0 => "a_0",
-            p => "p",
-            n => "n",
+    /// Particle symbol in UTF-8 format
+    pub const fn symbol(&self) -> Option<&'static str> {
+        // TODO: antiparticles?
+        if let Some(name) = self.elementary_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "mesons", feature = "full-names"))]
+        if let Some(name) = self.meson_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "baryons", feature = "full-names"))]
+        if let Some(name) = self.baryon_symbol() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "exotics", feature = "full-names"))]
+        if let Some(name) = self.exotic_symbol() {
+            return Some(name);
+        }
+        None
+    }
 
-            d_bar => r"\bar{d}",
-            u_bar => r"\bar{u}",
-            s_bar => r"\bar{s}",
-            c_bar => r"\bar{c}",
-            b_bar => r"\bar{b}",
-            t_bar => r"\bar{t}",
-            b_prime_bar => r"\bar{b}'",
-            t_prime_bar => r"\bar{t}'",
-            e_bar => r"e^+",
-            ν_e_bar => r"\bar{\nu}_e",
-            μ_bar => r"\mu^+",
-            ν_μ_bar => r"\bar{\nu}_\mu",
-            τ_bar => r"\tau^+",
-            ν_τ_bar => r"\bar{\nu}_\tau",
-            τ_prime_bar => r"\tau'^+",
-            ν_τ_prime_bar => r"\bar{\nu}_{\tau'}",
-            W_minus => "W^-",
-            W_prime_minus => "W^{--}",
-            H_minus => "H^-",
-            H_minus_minus => "H^{--}",
-            anti_proton => r"\bar{p}",
-            anti_neutron => r"\bar{p}",
+    /// Name of the associated particle
+    pub const fn name(&self) -> Option<&'static str> {
+        // TODO: many missing
+        if let Some(name) = self.elementary_name() {
+            return Some(name);
+        }
+        #[cfg(all(feature = "baryons", feature = "full-names"))]
+        if let Some(name) = self.baryon_name() {
+            return Some(name);
+        }
+        None
+    }
 
-            light_Ieq1_mesons::π_0 => r"\pi^0",
-            light_Ieq1_mesons::π_plus => r"\pi^+",
-            light_Ieq1_mesons::a_0_980_0 => r"a_0(980)^0",
-            light_Ieq1_mesons::a_0_980_plus => r"a_0(980)^+",
-            light_Ieq1_mesons::π_1300_0 => r"\pi(1300)^0",
-            light_Ieq1_mesons::π_1300_plus => r"\pi(1300)^+",
-            light_Ieq1_mesons::a_0_1450_0 => r"a_0(1450)^0",
-            light_Ieq1_mesons::a_0_1450_plus => r"a_0(1450)^+",
-            light_Ieq1_mesons::π_1800_0 => r"\pi(1800)^0",
-            light_Ieq1_mesons::π_1800_plus => r"\pi(1800)^+",
-            light_Ieq1_mesons::ρ_770_0 => r"\rho(770)^0",
-            light_Ieq1_mesons::ρ_770_plus => r"\rho(770)^+",
-            light_Ieq1_mesons::b_1_1235_0 => r"b_1(1235)^0",
-            light_Ieq1_mesons::b_1_1235_plus => r"b_1(1235)^+",
-            light_Ieq1_mesons::a_1_1260_0 => r"a_1(1260)^0",
-            light_Ieq1_mesons::a_1_1260_plus => r"a_1(1260)^+",
-            light_Ieq1_mesons::π_1_1400_0 => r"\pi_1(1400)^0",
-            light_Ieq1_mesons::π_1_1400_plus => r"\pi_1(1400)^+",
-            light_Ieq1_mesons::ρ_1450_0 => r"\rho(1450)^0",
-            light_Ieq1_mesons::ρ_1450_plus => r"\rho(1450)^+",
-            light_Ieq1_mesons::π_1_1600_0 => r"\pi_1(1600)^0",
-            light_Ieq1_mesons::π_1_1600_plus => r"\pi_1(1600)^+",
-            light_Ieq1_mesons::a_1_1640_0 => r"a_1(1640)^0",
-            light_Ieq1_mesons::a_1_1640_plus => r"a_1(1640)^+",
-            light_Ieq1_mesons::ρ_1700_0 => r"\rho(1700)^0",
-            light_Ieq1_mesons::ρ_1700_plus => r"\rho(1700)^+",
-            light_Ieq1_mesons::ρ_1900_0 => r"\rho(1900)^0",
-            light_Ieq1_mesons::ρ_1900_plus => r"\rho(1900)^+",
-            light_Ieq1_mesons::ρ_2150_0 => r"\rho(2150)^0",
-            light_Ieq1_mesons::ρ_2150_plus => r"\rho(2150)^+",
-            light_Ieq1_mesons::a_2_1320_0 => r"a_2(1320)^0",
-            light_Ieq1_mesons::a_2_1320_plus => r"a_2(1320)^+",
-            light_Ieq1_mesons::π_2_1670_0 => r"\pi_2(1670)^0",
-            light_Ieq1_mesons::π_2_1670_plus => r"\pi_2(1670)^+",
-            light_Ieq1_mesons::a_2_1700_0 => r"a_2(1700)^0",
-            light_Ieq1_mesons::a_2_1700_plus => r"a_2(1700)^+",
-            light_Ieq1_mesons::π_2_2100_0 => r"\pi_2(2100)^0",
-            light_Ieq1_mesons::π_2_2100_plus => r"\pi_2(2100)^+",
-            light_Ieq1_mesons::ρ_3_1690_0 => r"\rho_3(1690)^0",
-            light_Ieq1_mesons::ρ_3_1690_plus => r"\rho_3(1690)^+",
-            light_Ieq1_mesons::ρ_3_1990_0 => r"\rho_3(1990)^0",
-            light_Ieq1_mesons::ρ_3_1990_plus => r"\rho_3(1990)^+",
-            light_Ieq1_mesons::ρ_3_2250_0 => r"\rho_3(2250)^0",
-            light_Ieq1_mesons::ρ_3_2250_plus => r"\rho_3(2250)^+",
-            light_Ieq1_mesons::a_4_2040_0 => r"a_4(2040)^0",
-            light_Ieq1_mesons::a_4_2040_plus => r"a_4(2040)^+",
+    /// Get the corresponding integer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(photon.id(), 22);
+    /// ```
+    pub const fn id(self) -> i32 {
+        self.0
+    }
 
-            light_Ieq0_mesons::η => r"\eta",
-            light_Ieq0_mesons::η_prime_958 => r"\eta'(958)",
-            light_Ieq0_mesons::f_0_500 => "f_0(500)",
-            light_Ieq0_mesons::f_0_980 => "f_0(980)",
-            light_Ieq0_mesons::η_1295 => r"\eta(1295)",
-            light_Ieq0_mesons::f_0_1370 => "f_0(1370)",
-            light_Ieq0_mesons::η_1405 => r"\eta(1405)",
-            light_Ieq0_mesons::η_1475 => r"\eta(1475)",
-            light_Ieq0_mesons::f_0_1500 => "f_0(1500)",
-            light_Ieq0_mesons::f_0_1710 => "f_0(1710)",
-            light_Ieq0_mesons::η_1760 => r"\eta(1760)",
-            light_Ieq0_mesons::f_0_2020 => "f_0(2020)",
-            light_Ieq0_mesons::f_0_2100 => "f_0(2100)",
-            light_Ieq0_mesons::f_0_2200 => "f_0(2200)",
-            light_Ieq0_mesons::η_2225 => r"\eta(2225)",
-            light_Ieq0_mesons::ω_782 => r"\omega(782)",
-            light_Ieq0_mesons::φ_1020 => r"\phi(1020)",
-            light_Ieq0_mesons::h_1_1170 => r"h_1(1170)",
-            light_Ieq0_mesons::f_1_1285 => "f_1(1285)",
-            light_Ieq0_mesons::h_1_1380 => r"h_1(1380)",
-            light_Ieq0_mesons::f_1_1420 => "f_1(1420)",
-            light_Ieq0_mesons::ω_1420 => r"\omega(1420)",
-            light_Ieq0_mesons::f_1_1510 => "f_1(1510)",
-            light_Ieq0_mesons::h_1_1595 => r"h_1(1595)",
-            light_Ieq0_mesons::ω_1650 => r"\omega(1650)",
-            light_Ieq0_mesons::φ_1680 => r"\phi(1680)",
-            light_Ieq0_mesons::f_2_1270 => "f_2(1270)",
-            light_Ieq0_mesons::f_2_1430 => "f_2(1430)",
-            light_Ieq0_mesons::f_2_prime_1525 => "f_2'(1525)",
-            light_Ieq0_mesons::f_2_1565 => "f_2(1565)",
-            light_Ieq0_mesons::f_2_1640 => "f_2(1640)",
-            light_Ieq0_mesons::η_2_1645 => r"\eta_2(1645)",
-            light_Ieq0_mesons::f_2_1810 => "f_2(1810)",
-            light_Ieq0_mesons::η_2_1870 => r"\eta_2(1870)",
-            light_Ieq0_mesons::f_2_1910 => "f_2(1910)",
-            light_Ieq0_mesons::f_2_1950 => "f_2(1950)",
-            light_Ieq0_mesons::f_2_2010 => "f_2(2010)",
-            light_Ieq0_mesons::f_2_2150 => "f_2(2150)",
-            light_Ieq0_mesons::f_2_2300 => "f_2(2300)",
-            light_Ieq0_mesons::f_2_2340 => "f_2(2340)",
-            light_Ieq0_mesons::ω_3_1670 => r"\omega_3(1670)",
-            light_Ieq0_mesons::φ_3_1850 => r"\phi_3(1850)",
-            light_Ieq0_mesons::f_4_2050 => "f_4(2050)",
-            light_Ieq0_mesons::f_J_2220 => "f_J(2220)",
-            light_Ieq0_mesons::f_4_2300 => "f_4(2300)",
+    /// Get the corresponding anti-particle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(positron.anti(), electron);
+    /// assert_eq!(electron.anti(), positron);
+    /// ```
+    pub const fn anti(self) -> Self {
+        Self(-self.0)
+    }
 
-            strange_mesons::K_0_L => "K^0_L",
+    /// Get the corresponding particle for an anti-particle
+    ///
+    /// If not used on an anti-particle, returns the particle itself
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(positron.abs(), electron);
+    /// assert_eq!(electron.abs(), electron);
+    /// ```
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Compare by absolute value, with particles before anti-particles
+    ///
+    /// This gives the ordering 11, -11, 12, -12, …, which is the
+    /// ordering commonly used in generator tables. See also
+    /// [sort_by_abs].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(electron.cmp_by_abs(&positron), Ordering::Less);
+    /// assert_eq!(positron.cmp_by_abs(&nu_e), Ordering::Less);
+    /// assert_eq!(electron.cmp_by_abs(&electron), Ordering::Equal);
+    /// ```
+    pub fn cmp_by_abs(&self, other: &Self) -> std::cmp::Ordering {
+        (self.0.unsigned_abs(), self.is_anti_particle())
+            .cmp(&(other.0.unsigned_abs(), other.is_anti_particle()))
+    }
+
+    /// Check if this is an anti-particle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(!electron.is_anti_particle());
+    /// assert!(positron.is_anti_particle());
+    /// ```
+    pub const fn is_anti_particle(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Check if this is a gauge boson
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(photon.is_gauge_boson());
+    /// assert!(!electron.is_gauge_boson());
+    /// assert!(!Higgs.is_gauge_boson());
+    /// ```
+    pub const fn is_gauge_boson(&self) -> bool {
+        use gauge_and_higgs_bosons::*;
+        let abs_id = self.0.abs();
+        gluon.id() <= abs_id && abs_id <= W_plus.id()
+    }
+
+    /// Check if this is a photon
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(photon.is_photon());
+    /// assert!(!Z.is_photon());
+    /// ```
+    pub const fn is_photon(&self) -> bool {
+        self.0 == gauge_bosons::photon.0
+    }
+
+    /// Check if this is a dark photon
+    ///
+    /// The following codes are recognized: 55 (vector mediator in
+    /// the dark matter numbering scheme), 1023 (dark photon in the
+    /// Hidden Abelian Higgs Model), and 4900022 (hidden valley photon
+    /// in Pythia 8).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(ParticleID::new(4900022).is_dark_photon());
+    /// assert!(!photon.is_dark_photon());
+    /// ```
+    pub const fn is_dark_photon(&self) -> bool {
+        matches!(self.0, 55 | 1023 | 4900022)
+    }
+
+    /// Check if this is a quark
+    ///
+    /// Note that anti-quarks are not treated as quarks! Use `abs()`
+    /// if you want to include both quarks and anti-quarks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(top.is_quark());
+    /// assert!(down.is_quark());
+    /// assert!(!anti_top.is_quark());
+    /// assert!(anti_top.abs().is_quark());
+    /// assert!(anti_top.anti().is_quark());
+    /// ```
+    pub const fn is_quark(&self) -> bool {
+        use quarks::*;
+        down.id() <= self.id() && self.id() <= t_prime.id()
+    }
+
+    /// Check if this is an anti-quark
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(!top.is_anti_quark());
+    /// assert!(anti_top.is_anti_quark());
+    /// ```
+    pub const fn is_anti_quark(&self) -> bool {
+        self.anti().is_quark()
+    }
+
+    /// Check if this is a lepton
+    ///
+    /// Note that anti-leptons are not treated as leptons! Use `abs()`
+    /// if you want to include both leptons and anti-leptons.
+    ///
+    /// Both charged leptons and neutrinos are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(muon.is_lepton());
+    /// assert!(!anti_muon.is_lepton());
+    /// assert!(anti_muon.abs().is_lepton());
+    /// assert!(anti_muon.anti().is_lepton());
+    /// ```
+    pub const fn is_lepton(&self) -> bool {
+        use leptons::*;
+        electron.id() <= self.id() && self.id() <= tau_prime_neutrino.id()
+    }
+
+    /// Check if this is an anti-lepton
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(!muon.is_anti_lepton());
+    /// assert!(anti_muon.is_anti_lepton());
+    /// ```
+    pub const fn is_anti_lepton(&self) -> bool {
+        self.anti().is_lepton()
+    }
+
+    /// Check if this is a neutrino
+    ///
+    /// Note that anti-neutrinos are not treated as neutrinos! Use `abs()`
+    /// if you want to include both neutrinos and anti-neutrinos.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(muon_neutrino.is_neutrino());
+    /// assert!(!muon_anti_neutrino.is_neutrino());
+    /// ```
+    pub const fn is_neutrino(&self) -> bool {
+        self.is_lepton() && (self.id() & 1 == 0)
+    }
+
+    /// Check if this is an anti-neutrino
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(!muon_neutrino.is_anti_neutrino());
+    /// assert!(muon_anti_neutrino.is_anti_neutrino());
+    /// ```
+    pub const fn is_anti_neutrino(&self) -> bool {
+        self.anti().is_neutrino()
+    }
+
+    /// Check if this is a charged lepton
+    ///
+    /// Note that anti-leptons are not treated as leptons! Use `abs()`
+    /// if you want to include both leptons and anti-leptons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(muon.is_charged_lepton());
+    /// assert!(!electron_neutrino.is_charged_lepton());
+    /// ```
+    pub const fn is_charged_lepton(&self) -> bool {
+        self.is_lepton() && (self.id() & 1 == 1)
+    }
+
+    /// Check if this is a charged anti-lepton
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(!muon.is_charged_anti_lepton());
+    /// assert!(anti_muon.is_charged_anti_lepton());
+    /// ```
+    pub const fn is_charged_anti_lepton(&self) -> bool {
+        self.is_anti_particle() && self.anti().is_charged_lepton()
+    }
+
+    /// Generation of a quark or lepton
+    ///
+    /// The fourth-generation quarks b', t' and leptons τ', ν(τ') have
+    /// generation 4. Returns `None` for all other particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::quarks::t_prime;
+    /// assert_eq!(anti_up.generation(), Some(1));
+    /// assert_eq!(nu_mu.generation(), Some(2));
+    /// assert_eq!(bottom.generation(), Some(3));
+    /// assert_eq!(t_prime.generation(), Some(4));
+    /// assert_eq!(photon.generation(), None);
+    /// ```
+    pub const fn generation(&self) -> Option<u8> {
+        match self.0.unsigned_abs() {
+            1 | 2 | 11 | 12 => Some(1),
+            3 | 4 | 13 | 14 => Some(2),
+            5 | 6 | 15 | 16 => Some(3),
+            7..=9 | 17 | 18 => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Check if this is its own anti-particle
+    ///
+    /// This is the case for neutral gauge and Higgs bosons, the
+    /// graviton, Majorana superpartners like the neutralinos and the
+    /// gluino, flavorless neutral mesons like π⁰, η, and J/ψ, as well
+    /// as the neutral kaon mass eigenstates K⁰_L and K⁰_S. For these
+    /// particles, [anti](Self::anti) does not yield a meaningful
+    /// particle code. The sign of the code is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "susy"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::ccbar_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::susy_particles::*;
+    /// assert!(photon.is_self_conjugate());
+    /// assert!(Z.is_self_conjugate());
+    /// assert!(π_0.is_self_conjugate());
+    /// assert!(η.is_self_conjugate());
+    /// assert!(Jψ_1S.is_self_conjugate());
+    /// assert!(χ_tilde_0_1.is_self_conjugate());
+    /// assert!(!π_plus.is_self_conjugate());
+    /// assert!(!K_0.is_self_conjugate());
+    /// assert!(!nu_e.is_self_conjugate());
+    /// # }
+    /// ```
+    pub const fn is_self_conjugate(&self) -> bool {
+        self.abs().is_own_anti_particle()
+    }
+
+    /// Check if this is the charge conjugate of `other`
+    ///
+    /// Self-conjugate particles like the photon or the neutral pion
+    /// are their own charge conjugates. Note that the mass
+    /// eigenstates K⁰_L and K⁰_S are treated as self-conjugate and in
+    /// particular *not* as each other's conjugate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::strange_mesons::*;
+    /// assert!(electron.is_conjugate_of(positron));
+    /// assert!(!electron.is_conjugate_of(electron));
+    /// assert!(photon.is_conjugate_of(photon));
+    /// assert!(K_0.is_conjugate_of(K_0.anti()));
+    /// assert!(K_0_L.is_conjugate_of(K_0_L));
+    /// assert!(!K_0_L.is_conjugate_of(K_0_S));
+    /// # }
+    /// ```
+    pub const fn is_conjugate_of(&self, other: ParticleID) -> bool {
+        if self.is_own_anti_particle() {
+            self.0 == other.0
+        } else {
+            self.0 == -other.0
+        }
+    }
+
+    /// Check if this is a tetraquark
+    ///
+    /// Tetraquark codes have the form ±1 nᵣ n_L 0 n_q1 n_q2 n_q3 n_q4 n_J,
+    /// where n_q1, n_q2 are quarks and n_q3, n_q4 are anti-quarks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// // T_cc(3875)⁺ = ccū d̄
+    /// assert!(ParticleID::new(100044213).is_tetraquark());
+    /// assert!(!ParticleID::new(211).is_tetraquark());
+    /// ```
+    pub const fn is_tetraquark(&self) -> bool {
+        self.is_nine_digit_exotic()
+            && self.digit(5) == 0
+            && self.digit(4) > 0
+            && self.digit(3) > 0
+            && self.digit(2) > 0
+            && self.digit(1) > 0
+    }
+
+    /// Check if this is a pentaquark
+    ///
+    /// Pentaquark codes have the form ±1 nᵣ n_L n_q1 n_q2 n_q3 n_q4
+    /// n_q5 n_J, where n_q1, …, n_q4 are quarks and n_q5 is an
+    /// anti-quark.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "baryons", feature = "exotics"))] {
+    /// use particle_id::pentaquarks::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(Θ_plus.is_pentaquark());
+    /// assert!(Θ_plus.anti().is_pentaquark());
+    /// assert!(!proton.is_pentaquark());
+    /// # }
+    /// ```
+    pub const fn is_pentaquark(&self) -> bool {
+        self.is_nine_digit_exotic()
+            && self.digit(5) > 0
+            && self.digit(4) > 0
+            && self.digit(3) > 0
+            && self.digit(2) > 0
+            && self.digit(1) > 0
+    }
+
+    /// Number of constituent (anti-)quarks
+    ///
+    /// This is 2 for mesons and diquarks, 3 for baryons, 4 for
+    /// tetraquarks, and 5 for pentaquarks. Returns `None` for
+    /// elementary particles and all other codes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// assert_eq!(π_plus.constituent_count(), Some(2));
+    /// assert_eq!(proton.anti().constituent_count(), Some(3));
+    /// assert_eq!(electron.constituent_count(), None);
+    /// # }
+    /// ```
+    pub const fn constituent_count(&self) -> Option<u32> {
+        if self.is_pentaquark() {
+            Some(5)
+        } else if self.is_tetraquark() {
+            Some(4)
+        } else if self.has_baryon_digits() {
+            Some(3)
+        } else if self.has_meson_digits() || self.has_diquark_digits() {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    /// Check if this belongs to the Standard Model
+    ///
+    /// This includes quarks, leptons, gauge bosons, the Higgs boson,
+    /// hadrons, diquarks, nuclei, and reggeons. Note that codes that
+    /// are neither valid Standard Model nor BSM codes are classified as
+    /// neither.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(electron.is_sm());
+    /// assert!(proton.anti().is_sm());
+    /// assert!(!tau_prime.is_sm());
+    /// # }
+    /// ```
+    pub const fn is_sm(&self) -> bool {
+        match self.0.unsigned_abs() {
+            1..=6 | 11..=16 | 21..=25 => true,
+            // reggeon, pomeron, odderon
+            110 | 990 | 9990 => self.0 > 0,
+            _ => self.is_composite(),
+        }
+    }
+
+    /// Check if this is beyond the Standard Model
+    ///
+    /// This includes fourth-generation fermions, additional gauge
+    /// and Higgs bosons, the graviton, leptoquarks, dark matter
+    /// candidates, and the SUSY, technicolor, excited fermion, hidden
+    /// valley, and Kaluza-Klein blocks. Also included are the
+    /// left-right symmetric model particles 9900001–9900099 used by
+    /// Pythia 8 and the codes 9000001–9000099, which are commonly
+    /// assigned to new particles in user-defined models. Note that
+    /// codes that are neither valid Standard Model nor BSM codes are
+    /// classified as neither.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "baryons", feature = "susy"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::technicolor::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(!electron.is_bsm());
+    /// assert!(tau_prime.is_bsm());
+    /// assert!(g_tilde.is_bsm());
+    /// assert!(ρ_tc_0.is_bsm());
+    /// // hidden valley quark
+    /// assert!(ParticleID::new(4900101).is_bsm());
+    /// // right-handed W boson
+    /// assert!(ParticleID::new(-9900024).is_bsm());
+    ///
+    /// // check that a sample contains no exotica
+    /// let sample = [proton, electron, photon, W_plus];
+    /// assert!(sample.iter().all(|particle| !particle.is_bsm()));
+    /// # }
+    /// ```
+    pub const fn is_bsm(&self) -> bool {
+        matches!(
+            self.0.unsigned_abs(),
+            7 | 8
+                | 17
+                | 18
+                | 32..=42
+                | 51..=60
+                | 1_000_000..=5_999_999
+                | 9_000_001..=9_000_099
+                | 9_900_001..=9_900_099
+        )
+    }
+
+    /// Check if this is an elementary particle
+    ///
+    /// This includes quarks, leptons, gauge and Higgs bosons, as well
+    /// as elementary BSM particles like superpartners, Kaluza-Klein
+    /// excitations, the fields of left-right symmetric models
+    /// (99000xx), and the hidden-valley partners (49000xx) and
+    /// quarks (490010x).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "baryons", feature = "susy"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(electron.is_elementary());
+    /// assert!(W_minus.is_elementary());
+    /// assert!(g_tilde.is_elementary());
+    /// assert!(!proton.is_elementary());
+    /// // heavy neutrino and right-handed W boson
+    /// assert!(ParticleID::new(9900012).is_elementary());
+    /// assert!(ParticleID::new(-9900024).is_elementary());
+    /// // hidden valley quark, but not the hidden valley pion
+    /// assert!(ParticleID::new(4900101).is_elementary());
+    /// assert!(!ParticleID::new(4900111).is_elementary());
+    /// # }
+    /// ```
+    pub const fn is_elementary(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        let sm_like =
+            matches!(abs % 100, 1..=8 | 11..=18 | 21..=25 | 32..=42 | 51..=60);
+        if self.is_hidden_valley_quark() {
+            return true;
+        }
+        if matches!(abs, 9_900_001..=9_900_099 | 4_900_001..=4_900_099) {
+            return sm_like;
+        }
+        if abs >= 10_000_000 || self.digit(6) > 5 {
+            return false;
+        }
+        // nᵣ is only used for Kaluza-Klein excitations
+        if self.digit(5) != 0 && self.digit(6) != 5 {
+            return false;
+        }
+        if self.digit(4) != 0 || self.n_q1() != 0 || self.n_q2() != 0 {
+            return false;
+        }
+        sm_like
+    }
+
+    /// Check if this is a composite particle
+    ///
+    /// This includes hadrons, diquarks, and nuclei. Use
+    /// [is_diquark](Self::is_diquark) to treat diquarks differently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::diquarks::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(proton.is_composite());
+    /// assert!(ud_0.is_composite());
+    /// assert!(!electron.is_composite());
+    /// # }
+    /// ```
+    pub const fn is_composite(&self) -> bool {
+        self.has_meson_digits()
+            || self.has_baryon_digits()
+            || self.has_diquark_digits()
+            || self.is_tetraquark()
+            || self.is_pentaquark()
+            || self.has_nucleus_digits()
+    }
+
+    /// Check if this is a meson
+    ///
+    /// The check is based on the digits of the code, so it also
+    /// covers mesons for which this crate does not define a constant.
+    /// Meson codes have n_q1 = 0, non-zero n_q2 ≥ n_q3, and odd
+    /// n_J, i.e. integer spin. The mass eigenstates K⁰_L and K⁰_S are mesons, whereas
+    /// tetraquarks are not. Note that anti-mesons are also treated as
+    /// mesons, but negative codes of flavorless mesons are invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(π_plus.is_meson());
+    /// assert!(π_plus.anti().is_meson());
+    /// assert!(K_0_L.is_meson());
+    /// // D(2S)⁺
+    /// assert!(ParticleID::new(100411).is_meson());
+    /// assert!(!π_0.anti().is_meson());
+    /// assert!(!proton.is_meson());
+    /// assert!(!ParticleID::new(212).is_meson());
+    /// # }
+    /// ```
+    pub const fn is_meson(&self) -> bool {
+        self.has_meson_digits()
+    }
+
+    /// Check if this is a baryon
+    ///
+    /// The check is based on the digits of the code, so it also
+    /// covers baryons for which this crate does not define a constant.
+    /// Baryon codes have three non-zero quark digits and even n_J,
+    /// i.e. half-integer spin. Pentaquarks are included. Note that anti-baryons are also
+    /// treated as baryons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons", feature = "exotics"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::pentaquarks::*;
+    /// assert!(proton.is_baryon());
+    /// assert!(neutron.anti().is_baryon());
+    /// assert!(Θ_plus.is_baryon());
+    /// // N(1440)⁺
+    /// assert!(ParticleID::new(12212).is_baryon());
+    /// assert!(!π_plus.is_baryon());
+    /// assert!(!ParticleID::new(2213).is_baryon());
+    /// assert!(!ParticleID::new(2211).is_baryon());
+    /// # }
+    /// ```
+    pub const fn is_baryon(&self) -> bool {
+        self.has_baryon_digits() || self.is_pentaquark()
+    }
+
+    /// Check if this is a hadron
+    ///
+    /// This includes all [mesons](Self::is_meson),
+    /// [baryons](Self::is_baryon), and tetraquarks, independent of
+    /// whether this crate defines a constant for them. Nuclei are
+    /// excluded, see [is_hadron_or_nucleus](Self::is_hadron_or_nucleus).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// assert!(π_plus.is_hadron());
+    /// assert!(proton.anti().is_hadron());
+    /// // T_cc(3875)⁺ = ccū d̄
+    /// assert!(ParticleID::new(100044213).is_hadron());
+    /// // deuteron
+    /// assert!(!ParticleID::new(1000010020).is_hadron());
+    /// assert!(!gluon.is_hadron());
+    /// # }
+    /// ```
+    pub const fn is_hadron(&self) -> bool {
+        self.is_meson() || self.is_baryon() || self.is_tetraquark()
+    }
+
+    /// Check if this is a hadron or a nucleus
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// assert!(proton.is_hadron_or_nucleus());
+    /// // deuteron
+    /// assert!(ParticleID::new(1000010020).is_hadron_or_nucleus());
+    /// # }
+    /// ```
+    pub const fn is_hadron_or_nucleus(&self) -> bool {
+        self.is_hadron() || self.is_nucleus()
+    }
+
+    /// Check if this is a nucleus
+    ///
+    /// Nucleus codes have the form ±10LZZZAAAI, where L is the number
+    /// of strange quarks, Z the charge, A the baryon number, and I the
+    /// isomer level. Note that anti-nuclei are also treated as
+    /// nuclei. A single proton or neutron in this format,
+    /// e.g. 1000010010, is also a nucleus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// // deuteron
+    /// assert!(ParticleID::new(1000010020).is_nucleus());
+    /// // lead-208
+    /// assert!(ParticleID::new(1000822080).is_nucleus());
+    /// assert!(ParticleID::new(-1000020040).is_nucleus());
+    /// assert!(!proton.is_nucleus());
+    /// # }
+    /// ```
+    pub const fn is_nucleus(&self) -> bool {
+        self.has_nucleus_digits()
+    }
+
+    /// Check if this is an excited fermion
+    ///
+    /// Excited quarks and leptons from compositeness models have
+    /// codes 4000001–4000008 and 4000011–4000018, respectively. Note
+    /// that anti-particles are also treated as excited fermions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::excited_fermions::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(u_star.is_excited_fermion());
+    /// assert!(e_star.anti().is_excited_fermion());
+    /// assert!(!electron.is_excited_fermion());
+    /// ```
+    pub const fn is_excited_fermion(&self) -> bool {
+        matches!(
+            self.0.unsigned_abs(),
+            4_000_001..=4_000_008 | 4_000_011..=4_000_018
+        )
+    }
+
+    /// Check if this is a dark matter particle
+    ///
+    /// This includes all codes from 51 to 60, which are reserved for
+    /// dark matter candidates and their mediators. The spin of the
+    /// candidate is encoded in the code: 51 is a scalar, 52 a
+    /// spin-1/2 fermion, and 53 a vector. Codes 54 and 55 are scalar
+    /// and vector mediators. Note that anti-particles are also treated
+    /// as dark matter particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::dark_matter::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(χ_F.is_dark_matter());
+    /// assert!(χ_F.anti().is_dark_matter());
+    /// assert!(V_med.is_dark_matter());
+    /// assert!(ParticleID::new(60).is_dark_matter());
+    /// assert!(!nu_e.is_dark_matter());
+    /// ```
+    pub const fn is_dark_matter(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 51..=60)
+    }
+
+    /// Check if this is a technicolor particle
+    ///
+    /// Technicolor particles have codes in the 3000000 block. Note
+    /// that anti-particles are also treated as technicolor particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::technicolor::*;
+    /// use particle_id::light_mesons::*;
+    /// assert!(π_tc_plus.is_technicolor());
+    /// assert!(π_tc_plus.anti().is_technicolor());
+    /// assert!(ρ_22_tc.is_technicolor());
+    /// assert!(η_tc.is_technicolor());
+    /// assert!(!π_plus.is_technicolor());
+    /// # }
+    /// ```
+    pub const fn is_technicolor(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 3_000_001..=3_999_999)
+    }
+
+    /// Check if this is a diquark
+    ///
+    /// Note that anti-diquarks are also treated as diquarks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::diquarks::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(ud_0.is_diquark());
+    /// assert!(ud_0.anti().is_diquark());
+    /// assert!(!proton.is_diquark());
+    /// # }
+    /// ```
+    pub const fn is_diquark(&self) -> bool {
+        self.has_diquark_digits()
+    }
+
+    /// Check if this is a kaon
+    ///
+    /// This includes anti-kaons, excited states, and the mass
+    /// eigenstates K⁰_L, K⁰_S.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::light_mesons::*;
+    /// assert!(K_plus.anti().is_kaon());
+    /// assert!(K_0_L.is_kaon());
+    /// assert!(K_star_892_0.is_kaon());
+    /// assert!(!π_plus.is_kaon());
+    /// # }
+    /// ```
+    pub const fn is_kaon(&self) -> bool {
+        self.is_neutral_kaon_mass_eigenstate()
+            || (self.has_meson_digits()
+                && self.n_q2() == 3
+                && (self.n_q3() == 1 || self.n_q3() == 2))
+    }
+
+    /// Check if this is a pion
+    ///
+    /// This includes the excited pseudoscalar isovector states such
+    /// as π(1300).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::light_mesons::*;
+    /// assert!(π_plus.anti().is_pion());
+    /// assert!(π_0.is_pion());
+    /// assert!(π_1300_plus.is_pion());
+    /// assert!(!a_0_980_plus.is_pion());
+    /// assert!(!η.is_pion());
+    /// # }
+    /// ```
+    pub const fn is_pion(&self) -> bool {
+        self.has_meson_digits()
+            && self.n_j() == 1
+            && self.n_q3() == 1
+            && (self.n_q2() == 1 || self.n_q2() == 2)
+            && matches!(self.parity(), Some(Parity::Odd))
+    }
+
+    /// Check if this is a neutral kaon
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::strange_mesons::*;
+    /// assert!(K_0.anti().is_neutral_kaon());
+    /// assert!(K_0_S.is_neutral_kaon());
+    /// assert!(!K_plus.is_neutral_kaon());
+    /// # }
+    /// ```
+    pub const fn is_neutral_kaon(&self) -> bool {
+        self.is_kaon() && matches!(self.charge_in_thirds(), Some(0))
+    }
+
+    /// Check if this is one of the neutral kaon mass eigenstates K⁰_L, K⁰_S
+    ///
+    /// Unlike all other kaons, these states have no definite
+    /// strangeness and are their own anti-particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::strange_mesons::*;
+    /// assert!(K_0_L.is_neutral_kaon_mass_eigenstate());
+    /// assert!(!K_0.is_neutral_kaon_mass_eigenstate());
+    /// # }
+    /// ```
+    pub const fn is_neutral_kaon_mass_eigenstate(&self) -> bool {
+        self.0 == 130 || self.0 == 310
+    }
+
+    /// Check if this particle interacts electromagnetically
+    ///
+    /// This is the case for photons and all charged particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(electron.interacts_electromagnetically());
+    /// assert!(photon.interacts_electromagnetically());
+    /// assert!(proton.interacts_electromagnetically());
+    /// assert!(!neutron.interacts_electromagnetically());
+    /// assert!(!nu_e.interacts_electromagnetically());
+    /// # }
+    /// ```
+    pub const fn interacts_electromagnetically(&self) -> bool {
+        if self.0 == sm_elementary_particles::photon.0 {
+            return true;
+        }
+        matches!(self.charge_in_thirds(), Some(q) if q != 0)
+    }
+
+    /// Check if this particle interacts strongly
+    ///
+    /// This is the case for colored particles, hadrons, and nuclei.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(gluon.interacts_strongly());
+    /// assert!(anti_up.interacts_strongly());
+    /// assert!(neutron.interacts_strongly());
+    /// assert!(!electron.interacts_strongly());
+    /// # }
+    /// ```
+    pub const fn interacts_strongly(&self) -> bool {
+        self.color_multiplicity() > 1 || self.is_composite()
+    }
+
+    /// Check if this particle interacts weakly
+    ///
+    /// This is the case for quarks, leptons, the W, Z, and Higgs
+    /// bosons, and all particles made of quarks. Other BSM particles
+    /// are not considered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert!(nu_e.interacts_weakly());
+    /// assert!(Z.interacts_weakly());
+    /// assert!(neutron.interacts_weakly());
+    /// assert!(!photon.interacts_weakly());
+    /// assert!(!gluon.interacts_weakly());
+    /// # }
+    /// ```
+    pub const fn interacts_weakly(&self) -> bool {
+        self.is_composite()
+            || matches!(self.0.unsigned_abs(), 1..=8 | 11..=18 | 23..=25)
+    }
+
+    /// Nuclear code of a proton or neutron
+    ///
+    /// Protons and neutrons can also be written as nuclei with codes
+    /// 1000010010 and 1000000010, respectively. Returns `None` for
+    /// all other particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(proton.to_nuclear_code(), Some(ParticleID::new(1000010010)));
+    /// assert_eq!(
+    ///     neutron.anti().to_nuclear_code(),
+    ///     Some(ParticleID::new(-1000000010))
+    /// );
+    /// # }
+    /// ```
+    pub const fn to_nuclear_code(&self) -> Option<ParticleID> {
+        match self.0.unsigned_abs() {
+            2212 => Some(ParticleID(1000010010 * self.0.signum())),
+            2112 => Some(ParticleID(1000000010 * self.0.signum())),
+            _ => None,
+        }
+    }
+
+    /// Baryon code of a proton or neutron written as a nucleus
+    ///
+    /// This is the inverse of [to_nuclear_code](Self::to_nuclear_code).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(ParticleID::new(1000010010).to_nucleon_code(), Some(proton));
+    /// assert_eq!(proton.to_nucleon_code(), None);
+    /// # }
+    /// ```
+    pub const fn to_nucleon_code(&self) -> Option<ParticleID> {
+        match self.0.unsigned_abs() {
+            1000010010 => Some(ParticleID(2212 * self.0.signum())),
+            1000000010 => Some(ParticleID(2112 * self.0.signum())),
+            _ => None,
+        }
+    }
+
+    /// Normalize protons and neutrons to their baryon codes
+    ///
+    /// All other particles are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(ParticleID::new(1000000010).normalize_nucleon(), neutron);
+    /// assert_eq!(neutron.normalize_nucleon(), neutron);
+    /// # }
+    /// ```
+    pub const fn normalize_nucleon(&self) -> ParticleID {
+        match self.to_nucleon_code() {
+            Some(nucleon) => nucleon,
+            None => *self,
+        }
+    }
+
+    /// Normalize protons and neutrons to their nuclear codes
+    ///
+    /// All other particles are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(proton.normalize_nucleus(), ParticleID::new(1000010010));
+    /// # }
+    /// ```
+    pub const fn normalize_nucleus(&self) -> ParticleID {
+        match self.to_nuclear_code() {
+            Some(nucleus) => nucleus,
+            None => *self,
+        }
+    }
+
+    /// Check if a hadron has an established quark-model assignment
+    ///
+    /// Hadrons that do not fit into a quark-model multiplet or whose
+    /// assignment is uncertain are marked by a leading digit n = 9,
+    /// for example the f₀(500) with code 9000221. Returns `None` for
+    /// particles that are not mesons or baryons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(π_plus.has_quark_model_assignment(), Some(true));
+    /// assert_eq!(f_0_500.has_quark_model_assignment(), Some(false));
+    /// assert_eq!(electron.has_quark_model_assignment(), None);
+    /// # }
+    /// ```
+    pub const fn has_quark_model_assignment(&self) -> Option<bool> {
+        if self.has_meson_digits() || self.has_baryon_digits() {
+            Some(self.digit(6) != 9)
+        } else {
+            None
+        }
+    }
+
+    /// Check if two hadrons belong to the same isospin multiplet
+    ///
+    /// Members of the same multiplet only differ by the exchange of up
+    /// and down quarks. Returns `false` if either particle is not a
+    /// meson or baryon. K⁰_L and K⁰_S only belong to their own
+    /// multiplets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::strange_baryons::*;
+    /// assert!(π_plus.same_isospin_multiplet(π_0));
+    /// assert!(π_plus.same_isospin_multiplet(π_plus.anti()));
+    /// assert!(!π_0.same_isospin_multiplet(η));
+    /// assert!(K_plus.same_isospin_multiplet(K_0));
+    /// assert!(!K_plus.same_isospin_multiplet(K_0.anti()));
+    /// assert!(proton.same_isospin_multiplet(neutron));
+    /// assert!(Σ_plus.same_isospin_multiplet(Σ_0));
+    /// assert!(!Σ_0.same_isospin_multiplet(Λ));
+    /// # }
+    /// ```
+    pub const fn same_isospin_multiplet(&self, other: ParticleID) -> bool {
+        if self.0 == 130 || self.0 == 310 || other.0 == 130 || other.0 == 310 {
+            return self.0 == other.0;
+        }
+        match (self.isospin_multiplet_key(), other.isospin_multiplet_key()) {
+            (Some(a), Some(b)) => a.0 == b.0 && a.1 == b.1,
+            _ => false,
+        }
+    }
+
+    /// Check that the spin is consistent with the particle class
+    ///
+    /// Baryons, pentaquarks, and other states with an odd number of
+    /// quarks must have half-integer spin, whereas mesons, diquarks,
+    /// and tetraquarks must have integer spin. For other particles the
+    /// spin is implied by the code, so the check always succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::{Error, ParticleID};
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(proton.check_spin_statistics(), Ok(()));
+    /// // "proton" with integer spin
+    /// let corrupt = ParticleID::new(2211);
+    /// assert_eq!(corrupt.check_spin_statistics(), Err(Error::InvalidCode(2211)));
+    /// // "ρ⁺" with half-integer spin
+    /// let corrupt = ParticleID::new(212);
+    /// assert_eq!(corrupt.check_spin_statistics(), Err(Error::InvalidCode(212)));
+    /// # }
+    /// ```
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn check_spin_statistics(&self) -> Result<(), Error> {
+        let is_fermion =
+            if self.has_baryon_quark_digits() || self.is_pentaquark() {
+                true
+            } else if self.has_meson_quark_digits()
+                || self.has_diquark_digits()
+                || self.is_tetraquark()
+            {
+                false
+            } else {
+                return Ok(());
+            };
+        // K⁰_L and K⁰_S have n_J = 0
+        if self.0 == 130 || self.0 == 310 {
+            return Ok(());
+        }
+        // n_J = 2J + 1 is even for half-integer spin
+        if (self.n_j() % 2 == 0) == is_fermion {
+            Ok(())
+        } else {
+            Err(Error::InvalidCode(self.0))
+        }
+    }
+
+    /// Iterate over all structurally valid meson codes
+    ///
+    /// This includes codes that are not known to this crate, but
+    /// follow the numbering scheme for mesons: a quark and an
+    /// anti-quark with the heavier flavor first and an odd spin digit
+    /// n_J = 2J+1. Each particle is immediately followed by its
+    /// anti-particle, unless it is flavorless. K⁰_L and K⁰_S are
+    /// included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::light_mesons::*;
+    /// assert!(ParticleID::iter_valid_mesons().any(|p| p == π_plus.anti()));
+    /// assert!(ParticleID::iter_valid_mesons().all(|p| p.id() != 112));
+    /// # }
+    /// ```
+    pub fn iter_valid_mesons() -> impl Iterator<Item = ParticleID> {
+        let k_l_s = [ParticleID(130), ParticleID(310)];
+        let codes = [0, 9].into_iter().flat_map(|n| {
+            (0..100).flat_map(move |nr_nl| {
+                (1..=8).flat_map(move |q2| {
+                    (1..=q2).flat_map(move |q3| {
+                        (1..=9).step_by(2).map(move |j| {
+                            n * 1_000_000
+                                + nr_nl * 10_000
+                                + q2 * 100
+                                + q3 * 10
+                                + j
+                        })
+                    })
+                })
+            })
+        });
+        let mesons = codes
+            .flat_map(|id| [ParticleID(id), ParticleID(-id)])
+            .filter(|p| p.has_meson_digits());
+        k_l_s.into_iter().chain(mesons)
+    }
+
+    /// Iterate over all structurally valid baryon codes
+    ///
+    /// This includes codes that are not known to this crate, but
+    /// follow the numbering scheme for baryons: three quarks with the
+    /// heaviest flavor first and an even spin digit n_J = 2J+1. Each
+    /// particle is immediately followed by its anti-particle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::strange_baryons::*;
+    /// assert!(ParticleID::iter_valid_baryons().any(|p| p == Λ));
+    /// assert!(ParticleID::iter_valid_baryons().all(|p| p.id() != 1232));
+    /// # }
+    /// ```
+    pub fn iter_valid_baryons() -> impl Iterator<Item = ParticleID> {
+        let codes = [0, 9].into_iter().flat_map(|n| {
+            (0..100).flat_map(move |nr_nl| {
+                (1..=8).flat_map(move |q1| {
+                    (1..=q1).flat_map(move |q2| {
+                        (1..=q1).flat_map(move |q3| {
+                            (2..=8).step_by(2).map(move |j| {
+                                n * 1_000_000
+                                    + nr_nl * 10_000
+                                    + q1 * 1000
+                                    + q2 * 100
+                                    + q3 * 10
+                                    + j
+                            })
+                        })
+                    })
+                })
+            })
+        });
+        codes
+            .flat_map(|id| [ParticleID(id), ParticleID(-id)])
+            .filter(|p| p.has_baryon_digits())
+    }
+
+    /// Get the excited partner of a quark or lepton
+    ///
+    /// Returns `None` for fourth-generation fermions and all other
+    /// particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::excited_fermions::*;
+    /// assert_eq!(electron.excited_partner(), Some(e_star));
+    /// assert_eq!(positron.excited_partner(), Some(e_star.anti()));
+    /// assert_eq!(photon.excited_partner(), None);
+    /// ```
+    pub const fn excited_partner(&self) -> Option<ParticleID> {
+        match self.0.unsigned_abs() {
+            1..=6 | 11..=16 => {
+                Some(ParticleID(self.0 + self.0.signum() * 4_000_000))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the ground-state quark or lepton for an excited fermion
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::excited_fermions::*;
+    /// assert_eq!(e_star.ground_partner(), Some(electron));
+    /// assert_eq!(u_star.anti().ground_partner(), Some(anti_up));
+    /// assert_eq!(electron.ground_partner(), None);
+    /// ```
+    pub const fn ground_partner(&self) -> Option<ParticleID> {
+        match self.0.unsigned_abs() {
+            4_000_001..=4_000_006 | 4_000_011..=4_000_016 => {
+                Some(ParticleID(self.0 - self.0.signum() * 4_000_000))
+            }
+            _ => None,
+        }
+    }
+
+    /// Flavor quantum numbers
+    ///
+    /// The quantum numbers are derived from the quark content. They
+    /// vanish for leptons and bosons. Returns `None` if the quark
+    /// content is not known, e.g. for nuclei or the mixtures K⁰_L
+    /// and K⁰_S.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::FlavorNumbers;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::strange_mesons::*;
+    /// let k = K_plus.flavor().unwrap();
+    /// assert_eq!(k.s, 1);
+    /// assert_eq!(k.i3(), 0.5);
+    /// assert_eq!(b.flavor().unwrap().b, -1);
+    /// assert_eq!(electron.flavor(), Some(FlavorNumbers::default()));
+    /// assert_eq!(K_0_L.flavor(), None);
+    /// # }
+    /// ```
+    pub const fn flavor(&self) -> Option<FlavorNumbers> {
+        let Some([d, u, s, c, b, t]) = self.net_quark_numbers() else {
+            return None;
+        };
+        Some(FlavorNumbers {
+            i3_times_two: u - d,
+            s: -s,
+            c,
+            b: -b,
+            t,
+        })
+    }
+
+    /// Charm quantum number
+    ///
+    /// See [flavor](Self::flavor).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::mesons::*;
+    /// use particle_id::baryons::*;
+    /// assert_eq!(D_plus.charm(), Some(1));
+    /// assert_eq!(D_0.anti().charm(), Some(-1));
+    /// assert_eq!(Λ_c_plus.charm(), Some(1));
+    /// assert_eq!(Jψ_1S.charm(), Some(0));
+    /// assert_eq!(anti_charm.charm(), Some(-1));
+    /// # }
+    /// ```
+    pub const fn charm(&self) -> Option<i32> {
+        match self.flavor() {
+            Some(flavor) => Some(flavor.c),
+            None => None,
+        }
+    }
+
+    /// Heaviest constituent quark of an open-flavor hadron
+    ///
+    /// The result is an anti-quark if the heaviest constituent is an
+    /// anti-quark. Quarks are compared by their flavor code, so up is
+    /// considered heavier than down. Returns `None` for hadrons with
+    /// hidden flavor, where the heaviest quark and anti-quark have the
+    /// same flavor, and for particles that are not mesons or baryons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::mesons::*;
+    /// use particle_id::baryons::*;
+    /// assert_eq!(B_plus.heavy_quark(), Some(b_bar));
+    /// assert_eq!(D_0.heavy_quark(), Some(c));
+    /// assert_eq!(Λ_b_0.anti().heavy_quark(), Some(b_bar));
+    /// assert_eq!(Jψ_1S.heavy_quark(), None);
+    /// # }
+    /// ```
+    pub const fn heavy_quark(&self) -> Option<ParticleID> {
+        if let Some([q, qbar]) = self.meson_quarks() {
+            if q.0.unsigned_abs() > qbar.0.unsigned_abs() {
+                Some(q)
+            } else if q.0.unsigned_abs() < qbar.0.unsigned_abs() {
+                Some(qbar)
+            } else {
+                None
+            }
+        } else if let Some([q, ..]) = self.baryon_quarks() {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    /// Check if this is a neutral meson that oscillates into its
+    /// anti-particle
+    ///
+    /// This is the case for K⁰, D⁰, B⁰, B⁰_s and their anti-particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::mesons::*;
+    /// assert!(B_s_0.can_mix());
+    /// assert!(K_0.anti().can_mix());
+    /// assert!(!K_0_S.can_mix());
+    /// assert!(!B_plus.can_mix());
+    /// # }
+    /// ```
+    pub const fn can_mix(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 311 | 421 | 511 | 531)
+    }
+
+    /// Get the state this neutral meson oscillates into
+    ///
+    /// Returns `None` if this particle does not mix, see
+    /// [can_mix](Self::can_mix).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::mesons::*;
+    /// assert_eq!(B_0.mixing_partner(), Some(B_0.anti()));
+    /// assert_eq!(D_0.anti().mixing_partner(), Some(D_0));
+    /// assert_eq!(π_0.mixing_partner(), None);
+    /// # }
+    /// ```
+    pub const fn mixing_partner(&self) -> Option<ParticleID> {
+        if self.can_mix() {
+            Some(self.anti())
+        } else {
+            None
+        }
+    }
+
+    /// Number of internal degrees of freedom
+    ///
+    /// This is the number of spin states times the dimension of the
+    /// color representation. Massless vector bosons have two spin
+    /// states and Standard Model neutrinos have only one. Returns
+    /// `None` if the spin is not known. Use
+    /// [dof_with_anti_particle](Self::dof_with_anti_particle) to
+    /// include the anti-particle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(photon.dof(), Some(2));
+    /// assert_eq!(gluon.dof(), Some(16));
+    /// assert_eq!(Z.dof(), Some(3));
+    /// assert_eq!(top.dof(), Some(6));
+    /// assert_eq!(nu_e.dof(), Some(1));
+    /// ```
+    pub const fn dof(&self) -> Option<u32> {
+        let Some(two_j) = self.spin_times_two() else {
+            return None;
+        };
+        let spin_states = match self.0.unsigned_abs() {
+            // gluon, photon, graviton
+            21 | 22 | 39 => 2,
+            12 | 14 | 16 => 1,
+            _ => two_j + 1,
+        };
+        Some(spin_states * self.color_multiplicity())
+    }
+
+    /// Number of internal degrees of freedom including the anti-particle
+    ///
+    /// Same as [dof](Self::dof), but doubled unless the particle is
+    /// its own anti-particle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(photon.dof_with_anti_particle(), Some(2));
+    /// assert_eq!(top.dof_with_anti_particle(), Some(12));
+    /// assert_eq!(W_plus.dof_with_anti_particle(), Some(6));
+    /// ```
+    pub const fn dof_with_anti_particle(&self) -> Option<u32> {
+        match self.dof() {
+            Some(dof) if self.is_own_anti_particle() => Some(dof),
+            Some(dof) => Some(2 * dof),
+            None => None,
+        }
+    }
+
+    /// Constituent quarks and anti-quarks of a tetraquark
+    ///
+    /// The quarks come first, followed by the anti-quarks. Returns
+    /// `None` if this is not a tetraquark.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// // T_cc(3875)⁺ = ccū d̄
+    /// let t_cc = ParticleID::new(100044213);
+    /// assert_eq!(t_cc.tetraquark_content(), Some([c, c, u_bar, d_bar]));
+    /// assert_eq!(t_cc.spin_times_two(), Some(2));
+    /// ```
+    pub const fn tetraquark_content(&self) -> Option<[ParticleID; 4]> {
+        if !self.is_tetraquark() {
+            return None;
+        }
+        Some([
+            self.constituent_quark(self.digit(4), false),
+            self.constituent_quark(self.digit(3), false),
+            self.constituent_quark(self.digit(2), true),
+            self.constituent_quark(self.digit(1), true),
+        ])
+    }
+
+    /// Constituent quarks and anti-quark of a pentaquark
+    ///
+    /// The quarks come first, followed by the anti-quark. Returns
+    /// `None` if this is not a pentaquark.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "exotics")] {
+    /// use particle_id::pentaquarks::*;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(Θ_plus.pentaquark_content(), Some([u, u, d, d, s_bar]));
+    /// # }
+    /// ```
+    pub const fn pentaquark_content(&self) -> Option<[ParticleID; 5]> {
+        if !self.is_pentaquark() {
+            return None;
+        }
+        Some([
+            self.constituent_quark(self.digit(5), false),
+            self.constituent_quark(self.digit(4), false),
+            self.constituent_quark(self.digit(3), false),
+            self.constituent_quark(self.digit(2), false),
+            self.constituent_quark(self.digit(1), true),
+        ])
+    }
+}
+
+impl FromStr for ParticleID {
+    type Err = Error;
+
+    /// Parse a particle code or name
+    ///
+    /// Both signed numeric codes and names are accepted. Names can be
+    /// given in any of the [NameStyle]s except LaTeX, including
+    /// charge-suffixed symbols like "K-" or "mu+". A prefix "anti-"
+    /// denotes the anti-particle. It is an error to combine it with a
+    /// name that already refers to an anti-particle, like "anti-e+".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "full-names"))] {
+    /// use particle_id::{ParticleID, sm_elementary_particles::*};
+    /// use particle_id::strange_mesons::*;
+    /// assert_eq!("22".parse::<ParticleID>(), Ok(photon));
+    /// assert_eq!("photon".parse::<ParticleID>(), Ok(photon));
+    /// assert_eq!("-321".parse::<ParticleID>(), Ok(K_plus.anti()));
+    /// assert_eq!("K-".parse::<ParticleID>(), Ok(K_plus.anti()));
+    /// assert_eq!("mu+".parse::<ParticleID>(), Ok(anti_muon));
+    /// assert_eq!("anti-nu_e".parse::<ParticleID>(), Ok(nu_e_bar));
+    /// assert_eq!("anti-e-".parse::<ParticleID>(), Ok(positron));
+    /// assert!("anti-e+".parse::<ParticleID>().is_err());
+    /// assert!("photino".parse::<ParticleID>().is_err());
+    /// # }
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        if let Ok(id) = name.parse() {
+            return Ok(Self(id));
+        }
+        if let Some(particle) = index::find_by_name(name) {
+            return Ok(particle);
+        }
+        if let Some(name) = name.strip_prefix("anti-") {
+            if let Some(particle) = index::find_by_name(name) {
+                if particle.is_own_anti_particle() {
+                    return Ok(particle);
+                }
+                if !particle.is_anti_particle() {
+                    return Ok(particle.anti());
+                }
+            }
+        }
+        Err(Error::Parse(s.to_owned()))
+    }
+}
+
+impl TryFrom<i64> for ParticleID {
+    type Error = Error;
+
+    fn try_from(id: i64) -> Result<Self, Self::Error> {
+        i32::try_from(id)
+            .map(Self)
+            .map_err(|_| Error::OutOfRange(id))
+    }
+}
+
+impl ParticleID {
+    // Digits of the code ±n nᵣ n_L n_q1 n_q2 n_q3 n_J,
+    // counted from the right
+    const fn digit(&self, pos: u32) -> u32 {
+        (self.0.unsigned_abs() / 10u32.pow(pos)) % 10
+    }
+
+    const fn n_j(&self) -> u32 {
+        self.digit(0)
+    }
+
+    const fn n_q3(&self) -> u32 {
+        self.digit(1)
+    }
+
+    const fn n_q2(&self) -> u32 {
+        self.digit(2)
+    }
+
+    const fn n_q1(&self) -> u32 {
+        self.digit(3)
+    }
+
+    // Codes without the extra digits used for exotic states,
+    // i.e. with n ∈ {0, 9}
+    const fn is_hadron_like_code(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        abs > 100
+            && abs < 10_000_000
+            && (self.digit(6) == 0 || self.digit(6) == 9)
+    }
+
+    const fn has_meson_digits(&self) -> bool {
+        // K⁰_L and K⁰_S
+        if self.0 == 130 || self.0 == 310 {
+            return true;
+        }
+        self.has_meson_quark_digits() && self.n_j() % 2 == 1
+    }
+
+    // Quark digits of a meson, with any non-zero spin digit
+    const fn has_meson_quark_digits(&self) -> bool {
+        self.is_hadron_like_code()
+            && self.n_j() > 0
+            && self.n_q1() == 0
+            && self.n_q3() > 0
+            && self.n_q2() >= self.n_q3()
+            && self.n_q2() < 9
+            // flavorless mesons are their own anti-particles
+            && !(self.is_anti_particle() && self.n_q2() == self.n_q3())
+    }
+
+    #[allow(clippy::manual_is_multiple_of)]
+    const fn has_baryon_digits(&self) -> bool {
+        self.has_baryon_quark_digits() && self.n_j() % 2 == 0
+    }
+
+    // Quark digits of a baryon, with any non-zero spin digit
+    const fn has_baryon_quark_digits(&self) -> bool {
+        self.is_hadron_like_code()
+            && self.n_j() > 0
+            && self.n_q1() > 0
+            && self.n_q1() < 9
+            && self.n_q2() > 0
+            && self.n_q3() > 0
+    }
+
+    const fn has_diquark_digits(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        abs > 1000
+            && abs < 10_000
+            && self.n_j() > 0
+            && self.n_q3() == 0
+            && self.n_q2() > 0
+            && self.n_q1() >= self.n_q2()
+    }
+
+    // Constituent quark and anti-quark of a meson
+    //
+    // For a flavored meson with positive code, the heavier quark is
+    // a quark if it is up-type and an anti-quark if it is down-type.
+    #[allow(clippy::manual_is_multiple_of)]
+    const fn meson_quarks(&self) -> Option<[ParticleID; 2]> {
+        if !self.has_meson_digits() || self.0 == 130 || self.0 == 310 {
+            return None;
+        }
+        let heavy = self.n_q2();
+        let light = self.n_q3();
+        if heavy % 2 == 0 {
+            Some([
+                self.constituent_quark(heavy, false),
+                self.constituent_quark(light, true),
+            ])
+        } else {
+            Some([
+                self.constituent_quark(light, false),
+                self.constituent_quark(heavy, true),
+            ])
+        }
+    }
+
+    const fn baryon_quarks(&self) -> Option<[ParticleID; 3]> {
+        if !self.has_baryon_digits() {
+            return None;
+        }
+        Some([
+            self.constituent_quark(self.n_q1(), false),
+            self.constituent_quark(self.n_q2(), false),
+            self.constituent_quark(self.n_q3(), false),
+        ])
+    }
+
+    // Code shared by all members of an isospin multiplet, obtained by
+    // replacing up by down quarks, and a flag for Λ-like baryons
+    const fn isospin_multiplet_key(&self) -> Option<(i32, bool)> {
+        const fn to_down(q: u32) -> i32 {
+            if q == 2 {
+                1
+            } else {
+                q as i32
+            }
+        }
+
+        let rest = (self.0.unsigned_abs() / 10_000 * 10_000) as i32;
+        let n_j = self.n_j() as i32;
+        if self.has_meson_digits() {
+            let (q2, q3) = (self.n_q2(), self.n_q3());
+            // uū + dd̄ states are isospin singlets
+            let (q2, q3) = if q2 == 2 && q3 == 2 {
+                (2, 2)
+            } else {
+                (to_down(q2), to_down(q3))
+            };
+            let key = rest + 100 * q2 + 10 * q3 + n_j;
+            // light unflavored mesons and their anti-particles form
+            // the same multiplet
+            let sign = if self.n_q2() <= 2 { 1 } else { self.0.signum() };
+            Some((sign * key, false))
+        } else if self.has_baryon_digits() {
+            let (q1, q2, q3) = (self.n_q1(), self.n_q2(), self.n_q3());
+            let key = rest
+                + 1000 * to_down(q1)
+                + 100 * to_down(q2)
+                + 10 * to_down(q3)
+                + n_j;
+            Some((self.0.signum() * key, q2 < q3))
+        } else {
+            None
+        }
+    }
+
+    // Nuclear codes ±10LZZZAAAI
+    const fn has_nucleus_digits(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        let a = (abs / 10) % 1000;
+        let z = (abs / 10_000) % 1000;
+        1_000_000_000 <= abs && abs < 1_100_000_000 && a > 0 && a >= z
+    }
+
+    const fn is_nine_digit_exotic(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        100_000_000 <= abs && abs < 200_000_000 && self.n_j() > 0
+    }
+
+    /// Twice the spin
+    ///
+    /// For hadrons, the spin is taken from the last digit of the
+    /// particle code. Returns `None` if the spin is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// assert_eq!(electron.spin_times_two(), Some(1));
+    /// assert_eq!(gluon.spin_times_two(), Some(2));
+    /// assert_eq!(π_plus.spin_times_two(), Some(0));
+    /// assert_eq!(Δ_plus_plus.spin_times_two(), Some(3));
+    /// # }
+    /// ```
+    pub const fn spin_times_two(&self) -> Option<u32> {
+        if self.is_composite() && !self.has_nucleus_digits() {
+            // K⁰_L and K⁰_S have n_J = 0
+            return Some(self.n_j().saturating_sub(1));
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        let abs = self.0.unsigned_abs();
+        let two_j = match (self.digit(6), abs % 100) {
+            // squarks and sleptons
+            (1 | 2, 1..=18) => 0,
+            // gauginos
+            (1 | 2, 21..=37) => 1,
+            // gravitino
+            (1 | 2, 39) => 3,
+            // doubly charged Higgs bosons in left-right symmetric models
+            (9, 41 | 42) => 0,
+            (_, 1..=8 | 11..=18) => 1,
+            (_, 21..=24 | 32..=34 | 41) => 2,
+            (_, 25 | 35..=38 | 40 | 42) => 0,
+            (_, 39) => 4,
+            // dark matter: scalar, fermion, vector, scalar and vector mediator
+            (_, 51 | 54) => 0,
+            (_, 52) => 1,
+            (_, 53 | 55) => 2,
+            _ => return None,
+        };
+        Some(two_j)
+    }
+
+    // Dimension of the color representation
+    const fn color_multiplicity(&self) -> u32 {
+        if self.has_diquark_digits() {
+            return 3;
+        }
+        if !self.is_elementary() || self.is_hidden_valley_quark() {
+            return 1;
+        }
+        match (self.digit(6), self.0.unsigned_abs() % 100) {
+            // quarks, squarks, excited quarks, KK quarks
+            (_, 1..=8) => 3,
+            // gluon, gluino, KK gluon
+            (0 | 1 | 5, 21) => 8,
+            // leptoquark
+            (0, 42) => 3,
+            _ => 1,
+        }
+    }
+
+    /// Electric charge in units of e/3
+    ///
+    /// For hadrons, the charge is derived from the quark content
+    /// encoded in the particle code. Returns `None` if the charge is
+    /// not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(anti_down.charge_in_thirds(), Some(1));
+    /// assert_eq!(electron.charge_in_thirds(), Some(-3));
+    /// assert_eq!(proton.charge_in_thirds(), Some(3));
+    /// assert_eq!(W_minus.charge_in_thirds(), Some(-3));
+    /// # }
+    /// ```
+    pub const fn charge_in_thirds(&self) -> Option<i32> {
+        if self.0 == 130 || self.0 == 310 {
+            return Some(0);
+        }
+        if self.has_nucleus_digits() {
+            let z = ((self.0.unsigned_abs() / 10_000) % 1000) as i32;
+            return Some(3 * z * self.0.signum());
+        }
+        if self.is_composite() {
+            let Some([d, u, s, c, b, t]) = self.net_quark_numbers() else {
+                return None;
+            };
+            return Some(2 * (u + c + t) - (d + s + b));
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        if self.is_hidden_valley_quark() {
+            return Some(0);
+        }
+        let charge = match (self.digit(6), self.0.unsigned_abs() % 100) {
+            (9, 41 | 42) => 6,
+            (_, 1 | 3 | 5 | 7) => -1,
+            (_, 2 | 4 | 6 | 8) => 2,
+            (_, 11 | 13 | 15 | 17) => -3,
+            // charginos
+            (1 | 2, 24 | 37) => 3,
+            (1 | 2, _) => 0,
+            (_, 24 | 34 | 37) => 3,
+            (_, 38) => 6,
+            (_, 42) => -1,
+            _ => 0,
+        };
+        Some(charge * self.0.signum())
+    }
+
+    /// Baryon number in units of 1/3
+    ///
+    /// Quarks have baryon number 1/3 and baryons baryon number 1, so
+    /// this returns 1 for quarks, 3 for baryons, and 3A for nuclei
+    /// with mass number A. Anti-particles have the opposite sign.
+    /// Returns `None` if the baryon number is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// assert_eq!(up.baryon_number_in_thirds(), Some(1));
+    /// assert_eq!(anti_up.baryon_number_in_thirds(), Some(-1));
+    /// assert_eq!(proton.baryon_number_in_thirds(), Some(3));
+    /// assert_eq!(π_plus.baryon_number_in_thirds(), Some(0));
+    /// assert_eq!(electron.baryon_number_in_thirds(), Some(0));
+    /// # }
+    /// ```
+    pub const fn baryon_number_in_thirds(&self) -> Option<i32> {
+        if self.0 == 130 || self.0 == 310 {
+            return Some(0);
+        }
+        if self.has_nucleus_digits() {
+            let a = ((self.0.unsigned_abs() / 10) % 1000) as i32;
+            return Some(3 * a * self.0.signum());
+        }
+        if self.is_composite() {
+            let Some(n) = self.net_quark_numbers() else {
+                return None;
+            };
+            return Some(n[0] + n[1] + n[2] + n[3] + n[4] + n[5]);
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        if self.is_hidden_valley_quark() {
+            return Some(0);
+        }
+        match (self.digit(6), self.0.unsigned_abs() % 100) {
+            (_, 1..=8) | (0, 42) => Some(self.0.signum()),
+            _ => Some(0),
+        }
+    }
+
+    /// Total lepton number
+    ///
+    /// Returns `None` if the lepton number is not known. See also
+    /// [lepton_number](Self::lepton_number).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "baryons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(nu_tau.total_lepton_number(), Some(1));
+    /// assert_eq!(positron.total_lepton_number(), Some(-1));
+    /// assert_eq!(proton.total_lepton_number(), Some(0));
+    /// # }
+    /// ```
+    pub const fn total_lepton_number(&self) -> Option<i32> {
+        if self.is_composite() {
+            return Some(0);
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        match (self.digit(6), self.0.unsigned_abs() % 100) {
+            (_, 11..=18) | (0, 42) => Some(self.0.signum()),
+            _ => Some(0),
+        }
+    }
+
+    // Lepton numbers for the flavors e, μ, τ, τ'
+    const fn lepton_flavor_numbers(&self) -> [i32; 4] {
+        let mut n = [0; 4];
+        if self.is_elementary() {
+            let abs = self.0.unsigned_abs() % 100;
+            if 11 <= abs && abs <= 18 {
+                n[(abs as usize - 11) / 2] = self.0.signum();
+            }
+        }
+        n
+    }
+
+    // Triality of the color representation: 0, 1, or 2
+    const fn triality(&self) -> Option<u32> {
+        if self.has_diquark_digits() {
+            return Some(if self.is_anti_particle() { 1 } else { 2 });
+        }
+        if !self.is_elementary() && !self.is_composite() {
+            return None;
+        }
+        if self.color_multiplicity() != 3 {
+            return Some(0);
+        }
+        Some(if self.is_anti_particle() { 2 } else { 1 })
+    }
+
+    // Number of quarks minus number of anti-quarks for the flavors
+    // d, u, s, c, b, t
+    const fn net_quark_numbers(&self) -> Option<[i32; 6]> {
+        const fn add(n: &mut [i32; 6], q: ParticleID) {
+            let flavor = q.0.unsigned_abs() as usize;
+            if 1 <= flavor && flavor <= 6 {
+                n[flavor - 1] += q.0.signum();
+            }
+        }
+
+        let mut n = [0; 6];
+        if self.is_quark() || self.is_anti_quark() {
+            add(&mut n, *self);
+        } else if let Some([q1, q2]) = self.meson_quarks() {
+            add(&mut n, q1);
+            add(&mut n, q2);
+        } else if let Some([q1, q2, q3]) = self.baryon_quarks() {
+            add(&mut n, q1);
+            add(&mut n, q2);
+            add(&mut n, q3);
+        } else if self.has_diquark_digits() {
+            add(&mut n, self.constituent_quark(self.n_q1(), false));
+            add(&mut n, self.constituent_quark(self.n_q2(), false));
+        } else if let Some(content) = self.tetraquark_content() {
+            let mut i = 0;
+            while i < content.len() {
+                add(&mut n, content[i]);
+                i += 1;
+            }
+        } else if let Some(content) = self.pentaquark_content() {
+            let mut i = 0;
+            while i < content.len() {
+                add(&mut n, content[i]);
+                i += 1;
+            }
+        } else if !self.is_elementary() {
+            return None;
+        }
+        Some(n)
+    }
+
+    // Quark with the given flavor digit, conjugated for anti-quarks
+    // and anti-particles
+    const fn constituent_quark(&self, flavor: u32, anti: bool) -> ParticleID {
+        let q = ParticleID(flavor as i32);
+        if anti != self.is_anti_particle() {
+            q.anti()
+        } else {
+            q
+        }
+    }
+
+    // Hidden-valley quarks only carry hidden-sector quantum numbers
+    const fn is_hidden_valley_quark(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 4_900_101..=4_900_108)
+    }
+
+    const fn is_own_anti_particle(&self) -> bool {
+        use gauge_and_higgs_bosons::*;
+        use special_particles::*;
+        match self.0 {
+            // K⁰_L, K⁰_S
+            130 | 310 => return true,
+            // neutralinos, gluino, gravitino
+            1000021..=1000023 | 1000025 | 1000035 | 1000039 | 1000045 => {
+                return true
+            }
+            // dark matter mediators
+            54 | 55 => return true,
+            // neutral technicolor particles
+            3000111 | 3000113 | 3000221 | 3000223 | 3000331 | 3100021
+            | 3100111 | 3100113 | 3200111 | 3200113 | 3300113 | 3400113 => {
+                return true
+            }
+            _ => {}
+        }
+        match *self {
+            g | γ | Z | Z_prime | Z_prime_prime | h | H0 | A0 | a0 | G
+            | reggeon | pomeron | odderon => return true,
+            _ => {}
+        }
+        // quarkonia and other flavorless mesons
+        self.0 > 100
+            && self.0 < 10_000_000
+            && self.n_j() > 0
+            && self.n_q1() == 0
+            && self.n_q2() > 0
+            && self.n_q2() == self.n_q3()
+    }
+
+    const fn elementary_latex_symbol(&self) -> Option<&'static str> {
+        use sm_elementary_particles::*;
+
+        let name = match *self {
+            d => "d",
+            u => "u",
+            s => "s",
+            c => "c",
+            b => "b",
+            t => "t",
+            b_prime => "b'",
+            t_prime => "t'",
+            e => "e^-",
+            ν_e => r"\nu_e",
+            μ => r"\mu^-",
+            ν_μ => r"\nu_\mu",
+            τ => r"\tau^-",
+            ν_τ => r"\nu_\tau",
+            τ_prime => r"\tau'^-",
+            ν_τ_prime => r"\nu_{\tau'}",
+            g => "g",
+            γ => r"\gamma",
+            Z => r"Z",
+            W_plus => r"W^+",
+            h => "h",
+            Z_prime => "Z'",
+            Z_prime_prime => "Z''",
+            W_prime => "W'",
+            H0 => "H^0",
+            A0 => "A^0",
+            H_plus => "H^+",
+            H_plus_plus => "H^{++}",
+            a0 => "a_0",
+
+            d_bar => r"\bar{d}",
+            u_bar => r"\bar{u}",
+            s_bar => r"\bar{s}",
+            c_bar => r"\bar{c}",
+            b_bar => r"\bar{b}",
+            t_bar => r"\bar{t}",
+            b_prime_bar => r"\bar{b}'",
+            t_prime_bar => r"\bar{t}'",
+            e_bar => r"e^+",
+            ν_e_bar => r"\bar{\nu}_e",
+            μ_bar => r"\mu^+",
+            ν_μ_bar => r"\bar{\nu}_\mu",
+            τ_bar => r"\tau^+",
+            ν_τ_bar => r"\bar{\nu}_\tau",
+            τ_prime_bar => r"\tau'^+",
+            ν_τ_prime_bar => r"\bar{\nu}_{\tau'}",
+            W_minus => "W^-",
+            W_prime_minus => "W^{--}",
+            H_minus => "H^-",
+            H_minus_minus => "H^{--}",
+
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "mesons", feature = "full-names"))]
+    const fn meson_latex_symbol(&self) -> Option<&'static str> {
+        let name = match *self {
+            light_Ieq1_mesons::π_0 => r"\pi^0",
+            light_Ieq1_mesons::π_plus => r"\pi^+",
+            light_Ieq1_mesons::a_0_980_0 => r"a_0(980)^0",
+            light_Ieq1_mesons::a_0_980_plus => r"a_0(980)^+",
+            light_Ieq1_mesons::π_1300_0 => r"\pi(1300)^0",
+            light_Ieq1_mesons::π_1300_plus => r"\pi(1300)^+",
+            light_Ieq1_mesons::a_0_1450_0 => r"a_0(1450)^0",
+            light_Ieq1_mesons::a_0_1450_plus => r"a_0(1450)^+",
+            light_Ieq1_mesons::π_1800_0 => r"\pi(1800)^0",
+            light_Ieq1_mesons::π_1800_plus => r"\pi(1800)^+",
+            light_Ieq1_mesons::ρ_770_0 => r"\rho(770)^0",
+            light_Ieq1_mesons::ρ_770_plus => r"\rho(770)^+",
+            light_Ieq1_mesons::b_1_1235_0 => r"b_1(1235)^0",
+            light_Ieq1_mesons::b_1_1235_plus => r"b_1(1235)^+",
+            light_Ieq1_mesons::a_1_1260_0 => r"a_1(1260)^0",
+            light_Ieq1_mesons::a_1_1260_plus => r"a_1(1260)^+",
+            light_Ieq1_mesons::π_1_1400_0 => r"\pi_1(1400)^0",
+            light_Ieq1_mesons::π_1_1400_plus => r"\pi_1(1400)^+",
+            light_Ieq1_mesons::ρ_1450_0 => r"\rho(1450)^0",
+            light_Ieq1_mesons::ρ_1450_plus => r"\rho(1450)^+",
+            light_Ieq1_mesons::π_1_1600_0 => r"\pi_1(1600)^0",
+            light_Ieq1_mesons::π_1_1600_plus => r"\pi_1(1600)^+",
+            light_Ieq1_mesons::a_1_1640_0 => r"a_1(1640)^0",
+            light_Ieq1_mesons::a_1_1640_plus => r"a_1(1640)^+",
+            light_Ieq1_mesons::ρ_1700_0 => r"\rho(1700)^0",
+            light_Ieq1_mesons::ρ_1700_plus => r"\rho(1700)^+",
+            light_Ieq1_mesons::ρ_1900_0 => r"\rho(1900)^0",
+            light_Ieq1_mesons::ρ_1900_plus => r"\rho(1900)^+",
+            light_Ieq1_mesons::ρ_2150_0 => r"\rho(2150)^0",
+            light_Ieq1_mesons::ρ_2150_plus => r"\rho(2150)^+",
+            light_Ieq1_mesons::a_2_1320_0 => r"a_2(1320)^0",
+            light_Ieq1_mesons::a_2_1320_plus => r"a_2(1320)^+",
+            light_Ieq1_mesons::π_2_1670_0 => r"\pi_2(1670)^0",
+            light_Ieq1_mesons::π_2_1670_plus => r"\pi_2(1670)^+",
+            light_Ieq1_mesons::a_2_1700_0 => r"a_2(1700)^0",
+            light_Ieq1_mesons::a_2_1700_plus => r"a_2(1700)^+",
+            light_Ieq1_mesons::π_2_2100_0 => r"\pi_2(2100)^0",
+            light_Ieq1_mesons::π_2_2100_plus => r"\pi_2(2100)^+",
+            light_Ieq1_mesons::ρ_3_1690_0 => r"\rho_3(1690)^0",
+            light_Ieq1_mesons::ρ_3_1690_plus => r"\rho_3(1690)^+",
+            light_Ieq1_mesons::ρ_3_1990_0 => r"\rho_3(1990)^0",
+            light_Ieq1_mesons::ρ_3_1990_plus => r"\rho_3(1990)^+",
+            light_Ieq1_mesons::ρ_3_2250_0 => r"\rho_3(2250)^0",
+            light_Ieq1_mesons::ρ_3_2250_plus => r"\rho_3(2250)^+",
+            light_Ieq1_mesons::a_4_2040_0 => r"a_4(2040)^0",
+            light_Ieq1_mesons::a_4_2040_plus => r"a_4(2040)^+",
+
+            light_Ieq0_mesons::η => r"\eta",
+            light_Ieq0_mesons::η_prime_958 => r"\eta'(958)",
+            light_Ieq0_mesons::f_0_500 => "f_0(500)",
+            light_Ieq0_mesons::f_0_980 => "f_0(980)",
+            light_Ieq0_mesons::η_1295 => r"\eta(1295)",
+            light_Ieq0_mesons::f_0_1370 => "f_0(1370)",
+            light_Ieq0_mesons::η_1405 => r"\eta(1405)",
+            light_Ieq0_mesons::η_1475 => r"\eta(1475)",
+            light_Ieq0_mesons::f_0_1500 => "f_0(1500)",
+            light_Ieq0_mesons::f_0_1710 => "f_0(1710)",
+            light_Ieq0_mesons::η_1760 => r"\eta(1760)",
+            light_Ieq0_mesons::f_0_2020 => "f_0(2020)",
+            light_Ieq0_mesons::f_0_2100 => "f_0(2100)",
+            light_Ieq0_mesons::f_0_2200 => "f_0(2200)",
+            light_Ieq0_mesons::η_2225 => r"\eta(2225)",
+            light_Ieq0_mesons::ω_782 => r"\omega(782)",
+            light_Ieq0_mesons::φ_1020 => r"\phi(1020)",
+            light_Ieq0_mesons::h_1_1170 => r"h_1(1170)",
+            light_Ieq0_mesons::f_1_1285 => "f_1(1285)",
+            light_Ieq0_mesons::h_1_1380 => r"h_1(1380)",
+            light_Ieq0_mesons::f_1_1420 => "f_1(1420)",
+            light_Ieq0_mesons::ω_1420 => r"\omega(1420)",
+            light_Ieq0_mesons::f_1_1510 => "f_1(1510)",
+            light_Ieq0_mesons::h_1_1595 => r"h_1(1595)",
+            light_Ieq0_mesons::ω_1650 => r"\omega(1650)",
+            light_Ieq0_mesons::φ_1680 => r"\phi(1680)",
+            light_Ieq0_mesons::f_2_1270 => "f_2(1270)",
+            light_Ieq0_mesons::f_2_1430 => "f_2(1430)",
+            light_Ieq0_mesons::f_2_prime_1525 => "f_2'(1525)",
+            light_Ieq0_mesons::f_2_1565 => "f_2(1565)",
+            light_Ieq0_mesons::f_2_1640 => "f_2(1640)",
+            light_Ieq0_mesons::η_2_1645 => r"\eta_2(1645)",
+            light_Ieq0_mesons::f_2_1810 => "f_2(1810)",
+            light_Ieq0_mesons::η_2_1870 => r"\eta_2(1870)",
+            light_Ieq0_mesons::f_2_1910 => "f_2(1910)",
+            light_Ieq0_mesons::f_2_1950 => "f_2(1950)",
+            light_Ieq0_mesons::f_2_2010 => "f_2(2010)",
+            light_Ieq0_mesons::f_2_2150 => "f_2(2150)",
+            light_Ieq0_mesons::f_2_2300 => "f_2(2300)",
+            light_Ieq0_mesons::f_2_2340 => "f_2(2340)",
+            light_Ieq0_mesons::ω_3_1670 => r"\omega_3(1670)",
+            light_Ieq0_mesons::φ_3_1850 => r"\phi_3(1850)",
+            light_Ieq0_mesons::f_4_2050 => "f_4(2050)",
+            light_Ieq0_mesons::f_J_2220 => "f_J(2220)",
+            light_Ieq0_mesons::f_4_2300 => "f_4(2300)",
+
+            strange_mesons::K_0_L => "K^0_L",
             strange_mesons::K_0_S => "K^0_S",
             strange_mesons::K_0 => "K^0",
             strange_mesons::K_plus => "K^+",
@@ -1061,7 +3516,7 @@ impl ParticleID {
             bottom_mesons::B_1_L_0 => "B_1(L)^0",
             bottom_mesons::B_1_L_plus => "B_1(L)^+",
             bottom_mesons::B_1_H_0 => "B_1(H)^0",
-            bottom_mesons::B_1_H_plus => "B_1(L)^+",
+            bottom_mesons::B_1_H_plus => "B_1(H)^+",
             bottom_mesons::B_2_star0 => "B_2^{*0}",
             bottom_mesons::B_2_star_plus => "B_2^{*+}",
             bottom_mesons::B_s_0 => "B_s^0",
@@ -1090,6 +3545,8 @@ impl ParticleID {
             ccbar_mesons::ψ_4415 => r"\psi(4415)",
             ccbar_mesons::χ_c_2_1P => r"\chi_{c2}(1P)",
             ccbar_mesons::χ_c_2_3930 => r"\chi_{c2}(3930)",
+            ccbar_mesons::ψ_2_3823 => r"\psi_2(3823)",
+            ccbar_mesons::ψ_3_3842 => r"\psi_3(3842)",
 
             bbbar_mesons::η_b_1S => r"\eta_b(1S)",
             bbbar_mesons::χ_b_0_1P => r"\chi_{b0}(1P)",
@@ -1121,6 +3578,23 @@ impl ParticleID {
             bbbar_mesons::Υ_3_1D => r"\Upsilon_3(1D)",
             bbbar_mesons::Υ_3_2D => r"\Upsilon_3(2D)",
 
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "baryons", feature = "full-names"))]
+    const fn baryon_latex_symbol(&self) -> Option<&'static str> {
+        use light_anti_baryons::*;
+        use light_baryons::*;
+
+        let name = match *self {
+            p => "p",
+            n => "n",
+
+            anti_proton => r"\bar{p}",
+            anti_neutron => r"\bar{n}",
+
             light_baryons::Δ_plus_plus => r"\Delta^{++}",
             light_baryons::Δ_plus => r"\Delta^+",
             light_baryons::Δ_0 => r"\Delta^0",
@@ -1167,14 +3641,14 @@ impl ParticleID {
             bottom_baryons::Σ_b_0 => r"\Sigma_b^0",
             bottom_baryons::Σ_b_plus => r"\Sigma_b^+",
             bottom_baryons::Σ_b_star_minus => r"\Sigma_b^{*-}",
-            bottom_baryons::Σ_b_star_0 => r"\Sigma_b^0",
-            bottom_baryons::Σ_b_star_plus => r"\Sigma_b^+",
+            bottom_baryons::Σ_b_star_0 => r"\Sigma_b^{*0}",
+            bottom_baryons::Σ_b_star_plus => r"\Sigma_b^{*+}",
             bottom_baryons::Ξ_b_minus => r"\Xi_b^-",
             bottom_baryons::Ξ_b_0 => r"\Xi_b^0",
             bottom_baryons::Ξ_b_prime_minus => r"\Xi_b'^-",
-            bottom_baryons::Ξ_b_prime_0 => r"\Xi_b^0",
-            bottom_baryons::Ξ_b_star_minus => r"\Xi_b^-",
-            bottom_baryons::Ξ_b_star_0 => r"\Xi_b^0",
+            bottom_baryons::Ξ_b_prime_0 => r"\Xi_b'^0",
+            bottom_baryons::Ξ_b_star_minus => r"\Xi_b^{*-}",
+            bottom_baryons::Ξ_b_star_0 => r"\Xi_b^{*0}",
             bottom_baryons::Ω_b_minus => r"\Omega_b^-",
             bottom_baryons::Ω_b_star_minus => r"\Omega_b^{*-}",
             bottom_baryons::Ξ_b_c_0 => r"\Xi_{bc}^0",
@@ -1191,13 +3665,21 @@ impl ParticleID {
             bottom_baryons::Ξ_b_b_minus => r"\Xi_{bb}^-",
             bottom_baryons::Ξ_b_b_0 => r"\Xi_{bb}^0",
             bottom_baryons::Ξ_b_b_star_minus => r"\Xi_{bb}^{*-}",
-            bottom_baryons::Ξ_b_b_star_0 => r"\Xi_{bb}^{*-}",
+            bottom_baryons::Ξ_b_b_star_0 => r"\Xi_{bb}^{*0}",
             bottom_baryons::Ω_b_b_minus => r"\Omega_{bb}^-",
             bottom_baryons::Ω_b_b_star_minus => r"\Omega_{bb}^{*-}",
             bottom_baryons::Ω_b_b_c_0 => r"\Omega_{bbc}^0",
             bottom_baryons::Ω_b_b_c_star_0 => r"\Omega_{bbc}^{*0}",
             bottom_baryons::Ω_b_b_b_minus => r"\Omega_{bbb}^-",
 
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "exotics", feature = "full-names"))]
+    const fn exotic_latex_symbol(&self) -> Option<&'static str> {
+        let name = match *self {
             pentaquarks::Θ_plus => r"\Theta^+",
             pentaquarks::Φ_minus_minus => r"\Phi^{--}",
 
@@ -1206,11 +3688,7 @@ impl ParticleID {
         Some(name)
     }
 
-    /// Particle symbol in UTF-8 format
-    pub const fn symbol(&self) -> Option<&'static str> {
-        // TODO: antiparticles?
-        use light_anti_baryons::*;
-        use light_baryons::*;
+    const fn elementary_symbol(&self) -> Option<&'static str> {
         use sm_elementary_particles::*;
 
         let name = match *self {
@@ -1229,7 +3707,7 @@ impl ParticleID {
             τ => "τ",
             ν_τ => "ν(τ)",
             τ_prime => "τ'",
-            ν_τ_prime => "ν(τ)",
+            ν_τ_prime => "ν(τ')",
             g => "g",
             γ => "γ",
             Z => "Z",
@@ -1243,8 +3721,6 @@ impl ParticleID {
             H_plus => "H⁺",
             H_plus_plus => "H⁺⁺",
             a0 => "a₋",
-            p => "p",
-            n => "n",
 
             d_bar => r" ̅d",
             u_bar => r" ̅u",
@@ -1266,9 +3742,15 @@ impl ParticleID {
             W_prime_minus => "W⁻⁻",
             H_minus => "H⁻",
             H_minus_minus => "H⁻⁻",
-            anti_proton => " ̅p",
-            anti_neutron => " ̅n",
 
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "mesons", feature = "full-names"))]
+    const fn meson_symbol(&self) -> Option<&'static str> {
+        let name = match *self {
             light_Ieq1_mesons::π_0 => r"π⁰",
             light_Ieq1_mesons::π_plus => r"π⁺",
             light_Ieq1_mesons::a_0_980_0 => r"a₀(980)⁰",
@@ -1437,7 +3919,7 @@ impl ParticleID {
             bottom_mesons::B_1_L_0 => "B₁(L)⁰",
             bottom_mesons::B_1_L_plus => "B₁(L)⁺",
             bottom_mesons::B_1_H_0 => "B₁(H)⁰",
-            bottom_mesons::B_1_H_plus => "B₁(L)⁺",
+            bottom_mesons::B_1_H_plus => "B₁(H)⁺",
             bottom_mesons::B_2_star0 => "B₂⃰⁰",
             bottom_mesons::B_2_star_plus => "B₂⃰⁺",
             bottom_mesons::B_s_0 => "Bₛ⁰",
@@ -1466,6 +3948,8 @@ impl ParticleID {
             ccbar_mesons::ψ_4415 => r"ψ(4415)",
             ccbar_mesons::χ_c_2_1P => r"χ(c)₂(1P)",
             ccbar_mesons::χ_c_2_3930 => r"χ(c)₂(3930)",
+            ccbar_mesons::ψ_2_3823 => r"ψ₂(3823)",
+            ccbar_mesons::ψ_3_3842 => r"ψ₃(3842)",
 
             bbbar_mesons::η_b_1S => r"η(b)(1S)",
             bbbar_mesons::χ_b_0_1P => r"χ(b)₀(1P)",
@@ -1497,6 +3981,23 @@ impl ParticleID {
             bbbar_mesons::Υ_3_1D => r"Υ₃(1D)",
             bbbar_mesons::Υ_3_2D => r"Υ₃(2D)",
 
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "baryons", feature = "full-names"))]
+    const fn baryon_symbol(&self) -> Option<&'static str> {
+        use light_anti_baryons::*;
+        use light_baryons::*;
+
+        let name = match *self {
+            p => "p",
+            n => "n",
+
+            anti_proton => " ̅p",
+            anti_neutron => " ̅n",
+
             light_baryons::Δ_plus_plus => r"Δ⁺⁺",
             light_baryons::Δ_plus => r"Δ⁺",
             light_baryons::Δ_0 => r"Δ⁰",
@@ -1543,14 +4044,14 @@ impl ParticleID {
             bottom_baryons::Σ_b_0 => r"Σ(b)⁰",
             bottom_baryons::Σ_b_plus => r"Σ(b)⁺",
             bottom_baryons::Σ_b_star_minus => r"Σ(b)⃰⁻",
-            bottom_baryons::Σ_b_star_0 => r"Σ(b)⁰",
-            bottom_baryons::Σ_b_star_plus => r"Σ(b)⁺",
+            bottom_baryons::Σ_b_star_0 => r"Σ(b)⃰⁰",
+            bottom_baryons::Σ_b_star_plus => r"Σ(b)⃰⁺",
             bottom_baryons::Ξ_b_minus => r"Ξ(b)⁻",
             bottom_baryons::Ξ_b_0 => r"Ξ(b)⁰",
             bottom_baryons::Ξ_b_prime_minus => r"Ξ(b)'⁻",
-            bottom_baryons::Ξ_b_prime_0 => r"Ξ(b)⁰",
-            bottom_baryons::Ξ_b_star_minus => r"Ξ(b)⁻",
-            bottom_baryons::Ξ_b_star_0 => r"Ξ(b)⁰",
+            bottom_baryons::Ξ_b_prime_0 => r"Ξ(b)'⁰",
+            bottom_baryons::Ξ_b_star_minus => r"Ξ(b)⃰⁻",
+            bottom_baryons::Ξ_b_star_0 => r"Ξ(b)⃰⁰",
             bottom_baryons::Ω_b_minus => r"Ω(b)⁻",
             bottom_baryons::Ω_b_star_minus => r"Ω(b)⃰⁻",
             bottom_baryons::Ξ_b_c_0 => r"Ξ(bc)⁰",
@@ -1567,13 +4068,21 @@ impl ParticleID {
             bottom_baryons::Ξ_b_b_minus => r"Ξ(bb)⁻",
             bottom_baryons::Ξ_b_b_0 => r"Ξ(bb)⁰",
             bottom_baryons::Ξ_b_b_star_minus => r"Ξ(bb)⃰⁻",
-            bottom_baryons::Ξ_b_b_star_0 => r"Ξ(bb)⃰⁻",
+            bottom_baryons::Ξ_b_b_star_0 => r"Ξ(bb)⃰⁰",
             bottom_baryons::Ω_b_b_minus => r"Ω(bb)⁻",
             bottom_baryons::Ω_b_b_star_minus => r"Ω(bb)⃰⁻",
             bottom_baryons::Ω_b_b_c_0 => r"Ω(bbc)⁰",
             bottom_baryons::Ω_b_b_c_star_0 => r"Ω(bbc)⃰⁰",
             bottom_baryons::Ω_b_b_b_minus => r"Ω(bbb)⁻",
 
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    #[cfg(all(feature = "exotics", feature = "full-names"))]
+    const fn exotic_symbol(&self) -> Option<&'static str> {
+        let name = match *self {
             pentaquarks::Θ_plus => r"Θ⁺",
             pentaquarks::Φ_minus_minus => r"Φ⁻⁻",
 
@@ -1582,12 +4091,9 @@ impl ParticleID {
         Some(name)
     }
 
-    /// Name of the associated particle
-    pub const fn name(&self) -> Option<&'static str> {
-        // TODO: many missing
-        use light_anti_baryons::*;
-        use light_baryons::*;
+    const fn elementary_name(&self) -> Option<&'static str> {
         use sm_elementary_particles::*;
+
         let name = match *self {
             d => "down",
             u => "up",
@@ -1617,8 +4123,6 @@ impl ParticleID {
             A0 => "pseudoscalar Higgs",
             H_plus => "Higgs plus",
             H_plus_plus => "Higgs plus plus",
-            p => "proton",
-            n => "neutron",
 
             d_bar => "anti-down",
             u_bar => "anti-up",
@@ -1634,211 +4138,33 @@ impl ParticleID {
             ν_μ_bar => "muon anti-neutrino",
             τ_bar => "anti-tau",
             ν_τ_bar => "tau anti-neutrino",
-            τ_prime_bar => "tau prime",
+            τ_prime_bar => "anti-tau prime",
             ν_τ_prime_bar => "tau prime anti-neutrino",
             W_minus => "W minus",
-            W_prime_minus => "W minus minus",
+            W_prime_minus => "W prime minus",
             H_minus => "Higgs minus",
             H_minus_minus => "H minus minus",
-            p_bar => "anti-proton",
-            n_bar => "anti-neutron",
 
             _ => return None,
         };
         Some(name)
     }
 
-    /// Get the corresponding integer
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert_eq!(photon.id(), 22);
-    /// ```
-    pub const fn id(self) -> i32 {
-        self.0
-    }
-
-    /// Get the corresponding anti-particle
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert_eq!(positron.anti(), electron);
-    /// assert_eq!(electron.anti(), positron);
-    /// ```
-    pub const fn anti(self) -> Self {
-        Self(-self.0)
-    }
-
-    /// Get the corresponding particle for an anti-particle
-    ///
-    /// If not used on an anti-particle, returns the particle itself
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert_eq!(positron.abs(), electron);
-    /// assert_eq!(electron.abs(), electron);
-    /// ```
-    pub const fn abs(self) -> Self {
-        Self(self.0.abs())
-    }
-
-    /// Check if this is an anti-particle
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(!electron.is_anti_particle());
-    /// assert!(positron.is_anti_particle());
-    /// ```
-    pub const fn is_anti_particle(&self) -> bool {
-        self.0 < 0
-    }
-
-    /// Check if this is a gauge boson
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(photon.is_gauge_boson());
-    /// assert!(!electron.is_gauge_boson());
-    /// assert!(!Higgs.is_gauge_boson());
-    /// ```
-    pub const fn is_gauge_boson(&self) -> bool {
-        use gauge_and_higgs_bosons::*;
-        let abs_id = self.0.abs();
-        gluon.id() <= abs_id && abs_id <= W_plus.id()
-    }
-
-    /// Check if this is a quark
-    ///
-    /// Note that anti-quarks are not treated as quarks! Use `abs()`
-    /// if you want to include both quarks and anti-quarks.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(top.is_quark());
-    /// assert!(!anti_top.is_quark());
-    /// assert!(anti_top.abs().is_quark());
-    /// assert!(anti_top.anti().is_quark());
-    /// ```
-    pub const fn is_quark(&self) -> bool {
-        use quarks::*;
-        down.id() < self.id() && self.id() < t_prime.id()
-    }
-
-    /// Check if this is an anti-quark
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(!top.is_anti_quark());
-    /// assert!(anti_top.is_anti_quark());
-    /// ```
-    pub const fn is_anti_quark(&self) -> bool {
-        self.anti().is_quark()
-    }
-
-    /// Check if this is a lepton
-    ///
-    /// Note that anti-leptons are not treated as leptons! Use `abs()`
-    /// if you want to include both leptons and anti-leptons.
-    ///
-    /// Both charged leptons and neutrinos are included.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(muon.is_lepton());
-    /// assert!(!anti_muon.is_lepton());
-    /// assert!(anti_muon.abs().is_lepton());
-    /// assert!(anti_muon.anti().is_lepton());
-    /// ```
-    pub const fn is_lepton(&self) -> bool {
-        use leptons::*;
-        electron.id() <= self.id() && self.id() <= tau_prime_neutrino.id()
-    }
-
-    /// Check if this is an anti-lepton
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(!muon.is_anti_lepton());
-    /// assert!(anti_muon.is_anti_lepton());
-    /// ```
-    pub const fn is_anti_lepton(&self) -> bool {
-        self.anti().is_lepton()
-    }
-
-    /// Check if this is a neutrino
-    ///
-    /// Note that anti-neutrinos are not treated as neutrinos! Use `abs()`
-    /// if you want to include both neutrinos and anti-neutrinos.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(muon_neutrino.is_neutrino());
-    /// assert!(!muon_anti_neutrino.is_neutrino());
-    /// ```
-    pub const fn is_neutrino(&self) -> bool {
-        self.is_lepton() && (self.id() & 1 == 0)
-    }
+    #[cfg(all(feature = "baryons", feature = "full-names"))]
+    const fn baryon_name(&self) -> Option<&'static str> {
+        use light_anti_baryons::*;
+        use light_baryons::*;
 
-    /// Check if this is an anti-neutrino
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(!muon_neutrino.is_anti_neutrino());
-    /// assert!(muon_anti_neutrino.is_anti_neutrino());
-    /// ```
-    pub const fn is_anti_neutrino(&self) -> bool {
-        self.anti().is_neutrino()
-    }
+        let name = match *self {
+            p => "proton",
+            n => "neutron",
 
-    /// Check if this is a charged lepton
-    ///
-    /// Note that anti-leptons are not treated as leptons! Use `abs()`
-    /// if you want to include both leptons and anti-leptons.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(muon.is_charged_lepton());
-    /// assert!(!electron_neutrino.is_charged_lepton());
-    /// ```
-    pub const fn is_charged_lepton(&self) -> bool {
-        self.is_lepton() && (self.id() & 1 == 1)
-    }
+            p_bar => "anti-proton",
+            n_bar => "anti-neutron",
 
-    /// Check if this is a charged anti-lepton
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use particle_id::sm_elementary_particles::*;
-    /// assert!(!muon.is_charged_anti_lepton());
-    /// assert!(anti_muon.is_charged_anti_lepton());
-    /// ```
-    pub const fn is_charged_anti_lepton(&self) -> bool {
-        self.is_anti_particle() && self.anti().is_charged_lepton()
+            _ => return None,
+        };
+        Some(name)
     }
 }
 
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+use crate::index::{from_slot, slot, NUM_KNOWN};
+use crate::ParticleID;
+
+/// Map with particles as keys
+///
+/// Values for particles known to this crate are stored in a dense
+/// array, so lookup is as fast as array access. Other particles fall
+/// back to a hash map.
+///
+/// The iteration order is unspecified.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::ParticleMap;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let mut efficiency = ParticleMap::new();
+/// efficiency.insert(electron, 0.9);
+/// efficiency.insert(muon, 0.95);
+/// assert_eq!(efficiency.get(electron), Some(&0.9));
+/// assert_eq!(efficiency[muon], 0.95);
+/// assert_eq!(efficiency.get(tau), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParticleMap<T> {
+    dense: Vec<Option<T>>,
+    sparse: HashMap<ParticleID, T>,
+    dense_len: usize,
+}
+
+impl<T> ParticleMap<T> {
+    /// Create an empty map
+    pub fn new() -> Self {
+        let mut dense = Vec::new();
+        dense.resize_with(2 * NUM_KNOWN, || None);
+        Self {
+            dense,
+            sparse: HashMap::new(),
+            dense_len: 0,
+        }
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.dense_len + self.sparse.len()
+    }
+
+    /// Check if the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        self.dense.iter_mut().for_each(|v| *v = None);
+        self.dense_len = 0;
+        self.sparse.clear();
+    }
+
+    /// Insert a value, returning the previous value for the same particle
+    pub fn insert(&mut self, particle: ParticleID, value: T) -> Option<T> {
+        if let Some(slot) = slot(particle) {
+            let old = self.dense[slot].replace(value);
+            if old.is_none() {
+                self.dense_len += 1;
+            }
+            old
+        } else {
+            self.sparse.insert(particle, value)
+        }
+    }
+
+    /// Remove and return the value for the given particle
+    pub fn remove(&mut self, particle: ParticleID) -> Option<T> {
+        if let Some(slot) = slot(particle) {
+            let old = self.dense[slot].take();
+            if old.is_some() {
+                self.dense_len -= 1;
+            }
+            old
+        } else {
+            self.sparse.remove(&particle)
+        }
+    }
+
+    /// Get the value for the given particle
+    pub fn get(&self, particle: ParticleID) -> Option<&T> {
+        if let Some(slot) = slot(particle) {
+            self.dense[slot].as_ref()
+        } else {
+            self.sparse.get(&particle)
+        }
+    }
+
+    /// Get a mutable reference to the value for the given particle
+    pub fn get_mut(&mut self, particle: ParticleID) -> Option<&mut T> {
+        if let Some(slot) = slot(particle) {
+            self.dense[slot].as_mut()
+        } else {
+            self.sparse.get_mut(&particle)
+        }
+    }
+
+    /// Check if there is a value for the given particle
+    pub fn contains_key(&self, particle: ParticleID) -> bool {
+        self.get(particle).is_some()
+    }
+
+    /// Iterate over all particles and their values
+    pub fn iter(&self) -> impl Iterator<Item = (ParticleID, &T)> {
+        let dense =
+            self.dense.iter().enumerate().filter_map(|(slot, v)| {
+                v.as_ref().map(|v| (from_slot(slot), v))
+            });
+        dense.chain(self.sparse.iter().map(|(p, v)| (*p, v)))
+    }
+
+    /// Iterate over all particles and mutable references to their values
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (ParticleID, &mut T)> {
+        let dense =
+            self.dense.iter_mut().enumerate().filter_map(|(slot, v)| {
+                v.as_mut().map(|v| (from_slot(slot), v))
+            });
+        dense.chain(self.sparse.iter_mut().map(|(p, v)| (*p, v)))
+    }
+
+    /// Iterate over all particles in the map
+    pub fn keys(&self) -> impl Iterator<Item = ParticleID> + '_ {
+        self.iter().map(|(p, _)| p)
+    }
+
+    /// Iterate over all values in the map
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Iterate over mutable references to all values in the map
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.iter_mut().map(|(_, v)| v)
+    }
+}
+
+impl<T> Default for ParticleMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<ParticleID> for ParticleMap<T> {
+    type Output = T;
+
+    fn index(&self, particle: ParticleID) -> &Self::Output {
+        self.get(particle).expect("particle not in map")
+    }
+}
+
+impl<T> IndexMut<ParticleID> for ParticleMap<T> {
+    fn index_mut(&mut self, particle: ParticleID) -> &mut Self::Output {
+        self.get_mut(particle).expect("particle not in map")
+    }
+}
+
+impl<T> FromIterator<(ParticleID, T)> for ParticleMap<T> {
+    fn from_iter<I: IntoIterator<Item = (ParticleID, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<T> Extend<(ParticleID, T)> for ParticleMap<T> {
+    fn extend<I: IntoIterator<Item = (ParticleID, T)>>(&mut self, iter: I) {
+        for (particle, value) in iter {
+            self.insert(particle, value);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::baryons::*;
+use crate::mesons::*;
+use crate::sm_elementary_particles::*;
+use crate::{EnergyUnit, LengthUnit, ParticleID};
+
+/// Measured value with its uncertainty
+///
+/// For asymmetric uncertainties, the larger one is given.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Measurement {
+    /// Central value
+    pub value: f64,
+    /// Uncertainty
+    pub uncertainty: f64,
+}
+
+macro_rules! mass_width_table {
+    ($($particle:path => [$m:expr, $dm:expr], $width:expr,)*) => {
+        impl ParticleID {
+            /// Mass in GeV with its uncertainty
+            ///
+            /// Values are taken from the [Review of Particle
+            /// Physics](https://pdg.lbl.gov). Quark masses are given
+            /// in the MS-bar scheme, at a scale of 2 GeV for the light
+            /// quarks and at the quark mass for charm and bottom.
+            /// Returns `None` if the mass is not known.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use particle_id::sm_elementary_particles::*;
+            /// let mass = Z.mass_with_uncertainty().unwrap();
+            /// assert_eq!(mass.value, 91.188);
+            /// assert_eq!(mass.uncertainty, 0.002);
+            /// ```
+            pub const fn mass_with_uncertainty(&self) -> Option<Measurement> {
+                let (value, uncertainty) = match self.abs() {
+                    $($particle => ($m, $dm),)*
+                    _ => return None,
+                };
+                Some(Measurement { value, uncertainty })
+            }
+
+            /// Total decay width in GeV with its uncertainty
+            ///
+            /// The width of stable particles is zero. Returns `None`
+            /// if the width is not known.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use particle_id::sm_elementary_particles::*;
+            /// let width = Z.width_with_uncertainty().unwrap();
+            /// assert_eq!(width.value, 2.4955);
+            /// assert_eq!(width.uncertainty, 0.0023);
+            /// assert_eq!(electron.width(), Some(0.));
+            /// ```
+            pub const fn width_with_uncertainty(&self) -> Option<Measurement> {
+                let width: Option<(f64, f64)> = match self.abs() {
+                    $($particle => $width,)*
+                    _ => None,
+                };
+                match width {
+                    Some((value, uncertainty)) => {
+                        Some(Measurement { value, uncertainty })
+                    }
+                    None => None,
+                }
+            }
+        }
+    };
+}
+
+const STABLE: Option<(f64, f64)> = Some((0., 0.));
+
+mass_width_table!(
+    d => [4.70e-3, 0.48e-3], None,
+    u => [2.16e-3, 0.49e-3], None,
+    s => [93.5e-3, 0.8e-3], None,
+    c => [1.2730, 0.0046], None,
+    b => [4.183, 0.007], None,
+    t => [172.57, 0.29], Some((1.42, 0.19)),
+    e => [0.51099895000e-3, 0.00000000015e-3], STABLE,
+    μ => [0.1056583755, 0.0000000023], Some((2.9960e-19, 3.0e-25)),
+    τ => [1.77693, 0.00009], Some((2.2674e-12, 3.9e-15)),
+    g => [0., 0.], STABLE,
+    γ => [0., 0.], STABLE,
+    Z => [91.188, 0.002], Some((2.4955, 0.0023)),
+    W_plus => [80.3692, 0.0133], Some((2.085, 0.042)),
+    h => [125.20, 0.11], Some((3.7e-3, 1.9e-3)),
+    π_plus => [0.13957039, 0.00000018], Some((2.5284e-17, 4.9e-21)),
+    π_0 => [0.1349768, 0.0000005], Some((7.81e-9, 0.12e-9)),
+    η => [0.547862, 0.000017], Some((1.31e-6, 0.05e-6)),
+    η_prime_958 => [0.95778, 0.00006], Some((1.88e-4, 0.06e-4)),
+    ρ_770_0 => [0.77526, 0.00023], Some((0.1491, 0.0008)),
+    ρ_770_plus => [0.77526, 0.00023], Some((0.1491, 0.0008)),
+    ω_782 => [0.78266, 0.00013], Some((8.68e-3, 0.13e-3)),
+    φ_1020 => [1.019461, 0.000016], Some((4.249e-3, 0.013e-3)),
+    K_plus => [0.493677, 0.000015], Some((5.3167e-17, 8.6e-20)),
+    K_0 => [0.497611, 0.000013], None,
+    K_0_S => [0.497611, 0.000013], Some((7.3510e-15, 3.3e-18)),
+    K_0_L => [0.497611, 0.000013], Some((1.2866e-17, 5.3e-20)),
+    K_star_892_plus => [0.89167, 0.00026], Some((0.0514, 0.0008)),
+    K_star_892_0 => [0.89555, 0.00020], Some((0.0473, 0.0005)),
+    D_plus => [1.86966, 0.00005], Some((6.3718e-13, 3.1e-15)),
+    D_0 => [1.86484, 0.00005], Some((1.6042e-12, 3.9e-15)),
+    D_s_plus => [1.96835, 0.00007], Some((1.3133e-12, 5.8e-15)),
+    B_plus => [5.27934, 0.00012], Some((4.0184e-13, 9.8e-16)),
+    B_0 => [5.27966, 0.00012], Some((4.3389e-13, 1.1e-15)),
+    B_s_0 => [5.36692, 0.00010], Some((4.3303e-13, 1.4e-15)),
+    B_c_plus => [6.27447, 0.00032], Some((1.2906e-12, 2.3e-14)),
+    Jψ_1S => [3.096900, 0.000006], Some((92.6e-6, 1.7e-6)),
+    ψ_2S => [3.686097, 0.000011], Some((294e-6, 8e-6)),
+    Υ_1S => [9.46040, 0.00010], Some((54.02e-6, 1.25e-6)),
+    p => [0.93827208816, 0.00000000029], STABLE,
+    n => [0.93956542052, 0.00000000054], Some((7.4933e-28, 4.3e-31)),
+    Δ_plus_plus => [1.232, 0.002], Some((0.117, 0.003)),
+    Δ_plus => [1.232, 0.002], Some((0.117, 0.003)),
+    Δ_0 => [1.232, 0.002], Some((0.117, 0.003)),
+    Δ_minus => [1.232, 0.002], Some((0.117, 0.003)),
+    Λ => [1.115683, 0.000006], Some((2.5151e-15, 9.6e-18)),
+    Σ_plus => [1.18937, 0.00007], Some((8.2092e-15, 2.7e-17)),
+    Σ_0 => [1.192642, 0.000024], Some((8.89e-6, 0.84e-6)),
+    Σ_minus => [1.197449, 0.000030], Some((4.4504e-15, 3.3e-17)),
+    Ξ_0 => [1.31486, 0.00020], Some((2.2697e-15, 7.0e-17)),
+    Ξ_minus => [1.32171, 0.00007], Some((4.0159e-15, 3.7e-17)),
+    Ω_minus => [1.67245, 0.00029], Some((8.0172e-15, 1.1e-16)),
+    Λ_c_plus => [2.28646, 0.00014], Some((3.2488e-12, 1.6e-14)),
+    Λ_b_0 => [5.61960, 0.00017], Some((4.4746e-13, 2.7e-15)),
+);
+
+impl ParticleID {
+    /// Mass in GeV
+    ///
+    /// See [mass_with_uncertainty](Self::mass_with_uncertainty).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(top.mass(), Some(172.57));
+    /// assert_eq!(anti_top.mass(), top.mass());
+    /// assert_eq!(photon.mass(), Some(0.));
+    /// ```
+    pub const fn mass(&self) -> Option<f64> {
+        match self.mass_with_uncertainty() {
+            Some(mass) => Some(mass.value),
+            None => None,
+        }
+    }
+
+    /// Total decay width in GeV
+    ///
+    /// See [width_with_uncertainty](Self::width_with_uncertainty).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(W_plus.width(), Some(2.085));
+    /// ```
+    pub const fn width(&self) -> Option<f64> {
+        match self.width_with_uncertainty() {
+            Some(width) => Some(width.value),
+            None => None,
+        }
+    }
+}
+
+// ħ in GeV s
+const HBAR: f64 = 6.582119569e-25;
+// ħc in GeV m
+const HBAR_C: f64 = 1.973269804e-16;
+
+impl ParticleID {
+    /// Mass in the given unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::EnergyUnit;
+    /// use particle_id::light_mesons::*;
+    /// let mass = π_plus.mass_in(EnergyUnit::MeV).unwrap();
+    /// assert!((mass - 139.57039).abs() < 1e-9);
+    /// ```
+    pub const fn mass_in(&self, unit: EnergyUnit) -> Option<f64> {
+        match self.mass() {
+            Some(mass) => Some(mass / unit.in_gev()),
+            None => None,
+        }
+    }
+
+    /// Total decay width in the given unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::EnergyUnit;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let width = Z.width_in(EnergyUnit::MeV).unwrap();
+    /// assert!((width - 2495.5).abs() < 1e-9);
+    /// ```
+    pub const fn width_in(&self, unit: EnergyUnit) -> Option<f64> {
+        match self.width() {
+            Some(width) => Some(width / unit.in_gev()),
+            None => None,
+        }
+    }
+
+    /// Mean lifetime in seconds
+    ///
+    /// The lifetime is derived from the total width and is infinite
+    /// for stable particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// let lifetime = muon.lifetime().unwrap();
+    /// assert!((lifetime - 2.197e-6).abs() < 1e-9);
+    /// assert_eq!(electron.lifetime(), Some(f64::INFINITY));
+    /// ```
+    pub const fn lifetime(&self) -> Option<f64> {
+        match self.width() {
+            Some(width) => Some(HBAR / width),
+            None => None,
+        }
+    }
+
+    /// Mean decay length cτ in the given unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::LengthUnit;
+    /// use particle_id::strange_mesons::*;
+    /// let ctau = K_0_S.ctau_in(LengthUnit::Millimeter).unwrap();
+    /// assert!((ctau - 26.84).abs() < 0.01);
+    /// ```
+    pub const fn ctau_in(&self, unit: LengthUnit) -> Option<f64> {
+        match self.width() {
+            Some(width) => Some(HBAR_C / width / unit.in_meters()),
+            None => None,
+        }
+    }
+
+    /// Check if this particle leaves a track in a tracking detector
+    ///
+    /// This is the case for charged particles with a mean decay length
+    /// cτ of at least `min_ctau`, given in the specified unit. A common
+    /// choice is a minimum decay length of 10 mm. Returns `None` if
+    /// the charge or the width are not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::LengthUnit;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::strange_mesons::*;
+    /// use particle_id::strange_baryons::*;
+    /// let mm = LengthUnit::Millimeter;
+    /// assert_eq!(electron.leaves_track(10., mm), Some(true));
+    /// assert_eq!(K_plus.anti().leaves_track(10., mm), Some(true));
+    /// assert_eq!(Σ_plus.leaves_track(10., mm), Some(true));
+    /// assert_eq!(Σ_plus.leaves_track(100., mm), Some(false));
+    /// assert_eq!(K_0_S.leaves_track(10., mm), Some(false));
+    /// assert_eq!(W_plus.leaves_track(10., mm), Some(false));
+    /// ```
+    pub const fn leaves_track(
+        &self,
+        min_ctau: f64,
+        unit: LengthUnit,
+    ) -> Option<bool> {
+        let Some(charge) = self.charge_in_thirds() else {
+            return None;
+        };
+        let Some(ctau) = self.ctau_in(unit) else {
+            return None;
+        };
+        Some(charge != 0 && ctau >= min_ctau)
+    }
+}
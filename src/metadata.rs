@@ -0,0 +1,135 @@
+use crate::{ParticleID, ParticleMap};
+
+/// Store for user-defined metadata attached to particles
+///
+/// The metadata for particles known to this crate is kept in dense
+/// storage, see [ParticleMap]. Particles without explicit metadata
+/// fall back to an optional default value.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::MetadataStore;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// // k-factors
+/// let mut k_factors = MetadataStore::with_default(1.0);
+/// k_factors.insert_conjugates(top, 1.6);
+/// k_factors.insert(W_plus, 1.3);
+/// assert_eq!(k_factors.get(anti_top), Some(&1.6));
+/// assert_eq!(k_factors.get(W_plus), Some(&1.3));
+/// assert_eq!(k_factors.get(W_minus), Some(&1.0));
+/// assert!(!k_factors.contains_key(W_minus));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MetadataStore<T> {
+    values: ParticleMap<T>,
+    default: Option<T>,
+}
+
+impl<T> MetadataStore<T> {
+    /// Create a store without metadata and without a default
+    pub fn new() -> Self {
+        Self {
+            values: ParticleMap::new(),
+            default: None,
+        }
+    }
+
+    /// Create a store in which all particles have the given metadata
+    pub fn with_default(default: T) -> Self {
+        Self {
+            values: ParticleMap::new(),
+            default: Some(default),
+        }
+    }
+
+    /// Default metadata for particles without explicit metadata
+    pub fn default_value(&self) -> Option<&T> {
+        self.default.as_ref()
+    }
+
+    /// Set the default metadata, returning the previous default
+    pub fn set_default(&mut self, default: T) -> Option<T> {
+        self.default.replace(default)
+    }
+
+    /// Number of particles with explicit metadata
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if no particle has explicit metadata
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Attach metadata to a particle
+    ///
+    /// Returns the previous explicit metadata for this particle.
+    pub fn insert(&mut self, particle: ParticleID, value: T) -> Option<T> {
+        self.values.insert(particle, value)
+    }
+
+    /// Attach the same metadata to a particle and its anti-particle
+    ///
+    /// For particles that are their own anti-particles, this is the
+    /// same as [insert](Self::insert).
+    pub fn insert_conjugates(&mut self, particle: ParticleID, value: T)
+    where
+        T: Clone,
+    {
+        if !particle.abs().is_own_anti_particle() {
+            self.values.insert(particle.anti(), value.clone());
+        }
+        self.values.insert(particle, value);
+    }
+
+    /// Remove the explicit metadata for a particle
+    pub fn remove(&mut self, particle: ParticleID) -> Option<T> {
+        self.values.remove(particle)
+    }
+
+    /// Metadata for a particle, falling back to the default
+    pub fn get(&self, particle: ParticleID) -> Option<&T> {
+        self.values.get(particle).or(self.default.as_ref())
+    }
+
+    /// Mutable reference to the explicit metadata for a particle
+    pub fn get_mut(&mut self, particle: ParticleID) -> Option<&mut T> {
+        self.values.get_mut(particle)
+    }
+
+    /// Check if a particle has explicit metadata
+    pub fn contains_key(&self, particle: ParticleID) -> bool {
+        self.values.contains_key(particle)
+    }
+
+    /// Iterate over all particles with explicit metadata
+    ///
+    /// The iteration order is unspecified.
+    pub fn iter(&self) -> impl Iterator<Item = (ParticleID, &T)> {
+        self.values.iter()
+    }
+}
+
+impl<T> Default for MetadataStore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(ParticleID, T)> for MetadataStore<T> {
+    fn from_iter<I: IntoIterator<Item = (ParticleID, T)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+            default: None,
+        }
+    }
+}
+
+impl<T> Extend<(ParticleID, T)> for MetadataStore<T> {
+    fn extend<I: IntoIterator<Item = (ParticleID, T)>>(&mut self, iter: I) {
+        self.values.extend(iter)
+    }
+}
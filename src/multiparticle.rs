@@ -0,0 +1,144 @@
+//! Multiparticle definitions in process cards
+//!
+//! Event generators like MadGraph allow the user to define labels
+//! standing for several particles, for example `j = g u c d s u~ c~ d~
+//! s~` for a jet. The functions in this module convert between such
+//! definitions and [ParticleSet]s.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::multiparticle::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! let jet: Multiparticle = "j = g u c d s u~ c~ d~ s~".parse().unwrap();
+//! assert_eq!(jet.label, "j");
+//! assert_eq!(jet.particles.len(), 9);
+//! assert!(jet.particles.contains(anti_charm));
+//! assert_eq!(jet.to_string(), "j = d d~ u u~ s s~ c c~ g");
+//!
+//! // labels can refer to earlier definitions
+//! let card = "
+//!     define p = g u d u~ d~
+//!     define l+ = e+ mu+
+//!     define all = p l+ a
+//! ";
+//! let definitions = parse_definitions(card).unwrap();
+//! assert_eq!(definitions.len(), 3);
+//! assert_eq!(definitions[2].particles.len(), 8);
+//! assert!(definitions[2].particles.contains(photon));
+//! ```
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::{Error, NameStyle, ParticleSet};
+
+/// A label standing for a set of particles
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Multiparticle {
+    /// Label of the definition, e.g. "j"
+    pub label: String,
+    /// Particles the label stands for
+    pub particles: ParticleSet,
+}
+
+impl Multiparticle {
+    /// Construct a multiparticle from a label and a set of particles
+    pub fn new(label: impl Into<String>, particles: ParticleSet) -> Self {
+        Self {
+            label: label.into(),
+            particles,
+        }
+    }
+
+    /// Parse a definition that can refer to earlier definitions
+    ///
+    /// Each particle in the definition is either the label of one of
+    /// the `known` multiparticles or a particle name or code as
+    /// accepted by [ParticleID::from_str](crate::ParticleID::from_str).
+    /// Definitions can optionally start with the keyword `define`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::multiparticle::Multiparticle;
+    /// use particle_id::sm_elementary_particles::*;
+    ///
+    /// let leptons: Multiparticle = "l- = e- mu-".parse().unwrap();
+    /// let known = [leptons];
+    /// let def = Multiparticle::parse_with("define all = l- ta- 12", &known);
+    /// let all = def.unwrap();
+    /// assert_eq!(all.particles.as_slice(), [electron, nu_e, muon, tau]);
+    /// assert!(Multiparticle::parse_with("all = l- ta-", &[]).is_err());
+    /// ```
+    pub fn parse_with(
+        definition: &str,
+        known: &[Multiparticle],
+    ) -> Result<Self, Error> {
+        let line = definition.trim();
+        let line = match line.strip_prefix("define") {
+            Some(rest) if rest.starts_with(char::is_whitespace) => rest,
+            _ => line,
+        };
+        let Some((label, names)) = line.split_once('=') else {
+            return Err(Error::Parse(definition.to_owned()));
+        };
+        let label = label.trim();
+        if label.is_empty() || label.contains(char::is_whitespace) {
+            return Err(Error::Parse(definition.to_owned()));
+        }
+        let mut particles = ParticleSet::new();
+        for name in names.split_whitespace() {
+            if let Some(multiparticle) = known.iter().find(|m| m.label == name)
+            {
+                particles.extend(&multiparticle.particles);
+            } else {
+                particles.insert(name.parse()?);
+            }
+        }
+        Ok(Self::new(label, particles))
+    }
+}
+
+impl FromStr for Multiparticle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &[])
+    }
+}
+
+impl Display for Multiparticle {
+    /// Format as a compact definition
+    ///
+    /// Particles are written with their MadGraph names where
+    /// available, and as numeric codes otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} =", self.label)?;
+        for particle in &self.particles {
+            match particle.render_name(NameStyle::MadGraph) {
+                Some(name) => write!(f, " {name}")?,
+                None => write!(f, " {}", particle.id())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a sequence of multiparticle definitions
+///
+/// There has to be one definition per line. Empty lines and lines
+/// starting with `#` are skipped. Each definition can refer to the
+/// labels of the preceding ones, see
+/// [parse_with](Multiparticle::parse_with).
+pub fn parse_definitions(text: &str) -> Result<Vec<Multiparticle>, Error> {
+    let mut definitions = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let definition = Multiparticle::parse_with(line, &definitions)?;
+        definitions.push(definition);
+    }
+    Ok(definitions)
+}
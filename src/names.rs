@@ -0,0 +1,221 @@
+#[cfg(all(feature = "baryons", feature = "full-names"))]
+use crate::baryons::*;
+#[cfg(all(feature = "mesons", feature = "full-names"))]
+use crate::mesons::*;
+use crate::sm_elementary_particles::*;
+use crate::ParticleID;
+
+/// Naming conventions for particles
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum NameStyle {
+    /// Names used by the Particle Data Group
+    Pdg,
+    /// Names used by Pythia 8
+    Pythia8,
+    /// Names used by EvtGen
+    EvtGen,
+    /// Names used in LHCb decay descriptors
+    Lhcb,
+    /// Names used in the MadGraph Standard Model
+    MadGraph,
+    /// LaTeX symbols, see [latex_symbol](ParticleID::latex_symbol)
+    Latex,
+    /// UTF-8 symbols, see [symbol](ParticleID::symbol)
+    Utf8,
+    /// Plain ASCII names
+    Ascii,
+}
+
+macro_rules! style_name_table {
+    (
+        $(#[$attr:meta])*
+        $fn_name:ident:
+        $($particle:expr => [
+            $pdg:literal, $pythia8:literal, $evtgen:literal, $lhcb:literal, $ascii:literal
+        ],)*
+    ) => {
+        impl ParticleID {
+            $(#[$attr])*
+            const fn $fn_name(&self, style: NameStyle) -> Option<&'static str> {
+                $(
+                    if self.0 == $particle.0 {
+                        let name = match style {
+                            NameStyle::Pdg => $pdg,
+                            NameStyle::Pythia8 => $pythia8,
+                            NameStyle::EvtGen => $evtgen,
+                            NameStyle::Lhcb => $lhcb,
+                            NameStyle::Ascii => $ascii,
+                            _ => return None,
+                        };
+                        return Some(name);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+impl ParticleID {
+    /// Particle name in the given style
+    ///
+    /// Returns `None` if the particle has no known name in this style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "full-names"))] {
+    /// use particle_id::NameStyle;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::strange_mesons::*;
+    /// assert_eq!(nu_mu_bar.render_name(NameStyle::Pythia8), Some("nu_mubar"));
+    /// assert_eq!(nu_mu_bar.render_name(NameStyle::EvtGen), Some("anti-nu_mu"));
+    /// assert_eq!(nu_mu_bar.render_name(NameStyle::MadGraph), Some("vm~"));
+    /// assert_eq!(K_0_S.render_name(NameStyle::Lhcb), Some("KS0"));
+    /// assert_eq!(K_0_S.render_name(NameStyle::MadGraph), None);
+    /// assert_eq!(photon.render_name(NameStyle::Latex), Some(r"\gamma"));
+    /// # }
+    /// ```
+    pub const fn render_name(&self, style: NameStyle) -> Option<&'static str> {
+        match style {
+            NameStyle::Latex => return self.latex_symbol(),
+            NameStyle::Utf8 => return self.symbol(),
+            NameStyle::MadGraph => return self.madgraph_name(),
+            _ => {}
+        }
+        if let Some(name) = self.elementary_style_name(style) {
+            return Some(name);
+        }
+        #[cfg(all(feature = "mesons", feature = "full-names"))]
+        if let Some(name) = self.meson_style_name(style) {
+            return Some(name);
+        }
+        #[cfg(all(feature = "baryons", feature = "full-names"))]
+        if let Some(name) = self.baryon_style_name(style) {
+            return Some(name);
+        }
+        None
+    }
+
+    const fn madgraph_name(&self) -> Option<&'static str> {
+        let name = match *self {
+            d => "d",
+            u => "u",
+            s => "s",
+            c => "c",
+            b => "b",
+            t => "t",
+            e => "e-",
+            ν_e => "ve",
+            μ => "mu-",
+            ν_μ => "vm",
+            τ => "ta-",
+            ν_τ => "vt",
+            g => "g",
+            γ => "a",
+            Z => "z",
+            W_plus => "w+",
+            h => "h",
+
+            d_bar => "d~",
+            u_bar => "u~",
+            s_bar => "s~",
+            c_bar => "c~",
+            b_bar => "b~",
+            t_bar => "t~",
+            e_bar => "e+",
+            ν_e_bar => "ve~",
+            μ_bar => "mu+",
+            ν_μ_bar => "vm~",
+            τ_bar => "ta+",
+            ν_τ_bar => "vt~",
+            W_minus => "w-",
+
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+style_name_table!(
+    elementary_style_name:
+    d => ["d", "d", "d", "d", "d"],
+    u => ["u", "u", "u", "u", "u"],
+    s => ["s", "s", "s", "s", "s"],
+    c => ["c", "c", "c", "c", "c"],
+    b => ["b", "b", "b", "b", "b"],
+    t => ["t", "t", "t", "t", "t"],
+    e => ["e-", "e-", "e-", "e-", "e-"],
+    ν_e => ["nu(e)", "nu_e", "nu_e", "nu_e", "nu_e"],
+    μ => ["mu-", "mu-", "mu-", "mu-", "mu-"],
+    ν_μ => ["nu(mu)", "nu_mu", "nu_mu", "nu_mu", "nu_mu"],
+    τ => ["tau-", "tau-", "tau-", "tau-", "tau-"],
+    ν_τ => ["nu(tau)", "nu_tau", "nu_tau", "nu_tau", "nu_tau"],
+    g => ["g", "g", "g", "g", "g"],
+    γ => ["gamma", "gamma", "gamma", "gamma", "gamma"],
+    Z => ["Z0", "Z0", "Z0", "Z0", "Z"],
+    W_plus => ["W+", "W+", "W+", "W+", "W+"],
+    h => ["H0", "h0(H_1)", "Higgs0", "H_10", "h"],
+
+    d_bar => ["d~", "dbar", "anti-d", "d~", "d_bar"],
+    u_bar => ["u~", "ubar", "anti-u", "u~", "u_bar"],
+    s_bar => ["s~", "sbar", "anti-s", "s~", "s_bar"],
+    c_bar => ["c~", "cbar", "anti-c", "c~", "c_bar"],
+    b_bar => ["b~", "bbar", "anti-b", "b~", "b_bar"],
+    t_bar => ["t~", "tbar", "anti-t", "t~", "t_bar"],
+    e_bar => ["e+", "e+", "e+", "e+", "e+"],
+    ν_e_bar => ["nu(e)~", "nu_ebar", "anti-nu_e", "nu_e~", "nu_e_bar"],
+    μ_bar => ["mu+", "mu+", "mu+", "mu+", "mu+"],
+    ν_μ_bar => ["nu(mu)~", "nu_mubar", "anti-nu_mu", "nu_mu~", "nu_mu_bar"],
+    τ_bar => ["tau+", "tau+", "tau+", "tau+", "tau+"],
+    ν_τ_bar => ["nu(tau)~", "nu_taubar", "anti-nu_tau", "nu_tau~", "nu_tau_bar"],
+    W_minus => ["W-", "W-", "W-", "W-", "W-"],
+);
+
+style_name_table!(
+    #[cfg(all(feature = "mesons", feature = "full-names"))]
+    meson_style_name:
+    π_plus => ["pi+", "pi+", "pi+", "pi+", "pi+"],
+    π_0 => ["pi0", "pi0", "pi0", "pi0", "pi0"],
+    η => ["eta", "eta", "eta", "eta", "eta"],
+    K_plus => ["K+", "K+", "K+", "K+", "K+"],
+    K_0 => ["K0", "K0", "K0", "K0", "K0"],
+    K_0_S => ["K(S)0", "K_S0", "K_S0", "KS0", "K_S0"],
+    K_0_L => ["K(L)0", "K_L0", "K_L0", "KL0", "K_L0"],
+    D_plus => ["D+", "D+", "D+", "D+", "D+"],
+    D_0 => ["D0", "D0", "D0", "D0", "D0"],
+    B_plus => ["B+", "B+", "B+", "B+", "B+"],
+    B_0 => ["B0", "B0", "B0", "B0", "B0"],
+    Jψ_1S => ["J/psi(1S)", "J/psi", "J/psi", "J/psi(1S)", "J/psi"],
+
+    π_plus.anti() => ["pi-", "pi-", "pi-", "pi-", "pi-"],
+    K_plus.anti() => ["K-", "K-", "K-", "K-", "K-"],
+    K_0.anti() => ["K~0", "Kbar0", "anti-K0", "K~0", "K0_bar"],
+    D_plus.anti() => ["D-", "D-", "D-", "D-", "D-"],
+    D_0.anti() => ["D~0", "Dbar0", "anti-D0", "D~0", "D0_bar"],
+    B_plus.anti() => ["B-", "B-", "B-", "B-", "B-"],
+    B_0.anti() => ["B~0", "Bbar0", "anti-B0", "B~0", "B0_bar"],
+);
+
+style_name_table!(
+    #[cfg(all(feature = "baryons", feature = "full-names"))]
+    baryon_style_name:
+    p => ["p", "p+", "p+", "p+", "p"],
+    n => ["n", "n0", "n0", "n0", "n"],
+    Λ => ["Lambda", "Lambda0", "Lambda0", "Lambda0", "Lambda"],
+
+    p.anti() => ["p~", "pbar-", "anti-p-", "p~-", "p_bar"],
+    n.anti() => ["n~", "nbar0", "anti-n0", "n~0", "n_bar"],
+    Λ.anti() => ["Lambda~", "Lambdabar0", "anti-Lambda0", "Lambda~0", "Lambda_bar"],
+);
+
+// Styles tried when parsing names, in order of precedence
+pub(crate) const PARSE_STYLES: [NameStyle; 6] = [
+    NameStyle::Ascii,
+    NameStyle::Pdg,
+    NameStyle::Pythia8,
+    NameStyle::EvtGen,
+    NameStyle::Lhcb,
+    NameStyle::MadGraph,
+];
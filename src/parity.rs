@@ -0,0 +1,294 @@
+use std::ops::Mul;
+
+use crate::ParticleID;
+
+/// Eigenvalue of a discrete symmetry transformation such as parity
+///
+/// # Example
+///
+/// ```
+/// use particle_id::Parity;
+/// assert_eq!(Parity::Odd * Parity::Odd, Parity::Even);
+/// assert_eq!(Parity::Odd.sign(), -1);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Parity {
+    /// Eigenvalue +1
+    Even,
+    /// Eigenvalue -1
+    Odd,
+}
+
+impl Parity {
+    /// Eigenvalue as a sign ±1
+    pub const fn sign(&self) -> i32 {
+        match self {
+            Parity::Even => 1,
+            Parity::Odd => -1,
+        }
+    }
+
+    /// Parity with the eigenvalue (-1)ⁿ
+    #[allow(clippy::manual_is_multiple_of)]
+    pub const fn from_power(n: u32) -> Self {
+        if n % 2 == 0 {
+            Parity::Even
+        } else {
+            Parity::Odd
+        }
+    }
+
+    /// Opposite eigenvalue
+    pub const fn flipped(&self) -> Self {
+        match self {
+            Parity::Even => Parity::Odd,
+            Parity::Odd => Parity::Even,
+        }
+    }
+}
+
+impl Mul for Parity {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self == rhs {
+            Parity::Even
+        } else {
+            Parity::Odd
+        }
+    }
+}
+
+impl ParticleID {
+    /// Intrinsic parity
+    ///
+    /// By convention, fermions have positive and anti-fermions
+    /// negative parity. For mesons, the parity is derived from the
+    /// orbital angular momentum encoded in the particle code, except
+    /// for states without a quark-model assignment, which are looked
+    /// up in a table. Returns `None` if the parity is not known or
+    /// not defined, for example for the weak gauge bosons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::Parity;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(electron.parity(), Some(Parity::Even));
+    /// assert_eq!(positron.parity(), Some(Parity::Odd));
+    /// assert_eq!(photon.parity(), Some(Parity::Odd));
+    /// assert_eq!(π_plus.parity(), Some(Parity::Odd));
+    /// assert_eq!(a_1_1260_0.parity(), Some(Parity::Even));
+    /// assert_eq!(f_0_980.parity(), Some(Parity::Even));
+    /// assert_eq!(proton.anti().parity(), Some(Parity::Odd));
+    /// assert_eq!(W_plus.parity(), None);
+    /// # }
+    /// ```
+    pub const fn parity(&self) -> Option<Parity> {
+        if self.0 == 130 || self.0 == 310 {
+            return Some(Parity::Odd);
+        }
+        if self.has_meson_digits() {
+            if self.digit(6) == 9 {
+                return self.exotic_meson_parity();
+            }
+            let Some((l, _)) = self.meson_orbital_and_spin() else {
+                return None;
+            };
+            return Some(Parity::from_power(l + 1));
+        }
+        if self.has_baryon_digits() {
+            // only ground states have codes without excitation digits
+            if self.digit(4) != 0 || self.digit(5) != 0 || self.digit(6) != 0 {
+                return None;
+            }
+            return Some(self.fermion_parity());
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        match self.0.unsigned_abs() {
+            1..=8 | 11..=18 => Some(self.fermion_parity()),
+            21 | 22 => Some(Parity::Odd),
+            25 | 35 => Some(Parity::Even),
+            36 => Some(Parity::Odd),
+            _ => None,
+        }
+    }
+
+    /// Charge-conjugation parity
+    ///
+    /// Only particles that are their own anti-particles can have a
+    /// definite C-parity. For mesons, it is derived from the orbital
+    /// angular momentum and quark spin encoded in the particle code,
+    /// except for states without a quark-model assignment, which are
+    /// looked up in a table. Returns `None` if the C-parity is not
+    /// known or not defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::Parity;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::ccbar_mesons::*;
+    /// assert_eq!(photon.c_parity(), Some(Parity::Odd));
+    /// assert_eq!(π_0.c_parity(), Some(Parity::Even));
+    /// assert_eq!(Jψ_1S.c_parity(), Some(Parity::Odd));
+    /// assert_eq!(χ_c_1_1P.c_parity(), Some(Parity::Even));
+    /// assert_eq!(h_1_1595.c_parity(), Some(Parity::Odd));
+    /// assert_eq!(π_plus.c_parity(), None);
+    /// # }
+    /// ```
+    pub const fn c_parity(&self) -> Option<Parity> {
+        if !self.is_own_anti_particle() {
+            return None;
+        }
+        if self.has_meson_digits() {
+            if self.digit(6) == 9 {
+                return self.exotic_meson_c_parity();
+            }
+            let Some((l, s)) = self.meson_orbital_and_spin() else {
+                return None;
+            };
+            return Some(Parity::from_power(l + s));
+        }
+        match self.0 {
+            22 => Some(Parity::Odd),
+            25 | 35 | 36 => Some(Parity::Even),
+            _ => None,
+        }
+    }
+
+    /// G-parity of light unflavored mesons
+    ///
+    /// The G-parity is derived from the isospin I and the C-parity of
+    /// the neutral member of the isospin multiplet as G = C (-1)ᴵ.
+    /// Returns `None` for all other particles and if the C-parity is
+    /// not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::Parity;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// assert_eq!(π_plus.g_parity(), Some(Parity::Odd));
+    /// assert_eq!(π_0.g_parity(), Some(Parity::Odd));
+    /// assert_eq!(ρ_770_plus.anti().g_parity(), Some(Parity::Even));
+    /// assert_eq!(ω_782.g_parity(), Some(Parity::Odd));
+    /// assert_eq!(η.g_parity(), Some(Parity::Even));
+    /// assert_eq!(K_plus.g_parity(), None);
+    /// # }
+    /// ```
+    pub const fn g_parity(&self) -> Option<Parity> {
+        if !self.has_meson_digits() || self.0 == 130 || self.0 == 310 {
+            return None;
+        }
+        // neutral member of the isospin multiplet and isospin
+        let abs = self.0.unsigned_abs() as i32;
+        let (neutral, isospin) = match (self.n_q2(), self.n_q3()) {
+            (1, 1) => (abs, 1),
+            (2, 1) => (abs - 100, 1),
+            (2, 2) | (3, 3) => (abs, 0),
+            _ => return None,
+        };
+        match ParticleID(neutral).c_parity() {
+            Some(c) if isospin == 1 => Some(c.flipped()),
+            c => c,
+        }
+    }
+
+    const fn fermion_parity(&self) -> Parity {
+        if self.is_anti_particle() {
+            Parity::Odd
+        } else {
+            Parity::Even
+        }
+    }
+
+    // Orbital angular momentum L and total quark spin S of a meson,
+    // following the n_L digit convention
+    const fn meson_orbital_and_spin(&self) -> Option<(u32, u32)> {
+        if !self.has_meson_digits() || self.digit(6) == 9 {
+            return None;
+        }
+        let Some(two_j) = self.spin_times_two() else {
+            return None;
+        };
+        let j = two_j / 2;
+        let (l, s) = match (j, self.digit(4)) {
+            (0, 0) => (0, 0),
+            (0, 1) => (1, 1),
+            (_, 0) => (j - 1, 1),
+            (_, 1) => (j, 0),
+            (_, 2) => (j, 1),
+            (_, 3) => (j + 1, 1),
+            _ => return None,
+        };
+        Some((l, s))
+    }
+
+    // Parity of mesons without a quark-model assignment
+    const fn exotic_meson_parity(&self) -> Option<Parity> {
+        match self.0.unsigned_abs() {
+            // a₀(980), a₁(1640), a₂(1700)
+            9000111 | 9000211 | 9020113 | 9020213 | 9000115 | 9000215
+            // f₀ states, f₁(1510), h₁(1595)
+            | 9000221 | 9010221 | 9030221 | 9050221 | 9060221 | 9070221
+            | 9000223 | 9010223
+            // f₂ states, f_J(2220), f₄(2300)
+            | 9000225 | 9010225 | 9020225 | 9030225 | 9040225 | 9050225
+            | 9060225 | 9070225 | 9080225 | 9090225 | 9000229 | 9010229
+            // K₀*(700), K₀*(1950), K₁(1650), K₂*(1980), K₃(2320)
+            | 9000311 | 9000321 | 9020311 | 9020321 | 9000313 | 9000323
+            | 9010315 | 9010325 | 9010317 | 9010327 => Some(Parity::Even),
+            // π(1800), π₁(1400), π₁(1600), ρ(1900), ρ(2150), π₂(2100),
+            // ρ₃(1990), ρ₃(2250)
+            9010111 | 9010211 | 9000113 | 9000213 | 9010113 | 9010213
+            | 9030113 | 9030213 | 9040113 | 9040213 | 9010115 | 9010215
+            | 9000117 | 9000217 | 9010117 | 9010217
+            // η(1405), η(1760), η(2225)
+            | 9020221 | 9040221 | 9080221
+            // K(1830), K₂(1580), K₂(2250), K₄(2500)
+            | 9010311 | 9010321 | 9000315 | 9000325 | 9020315 | 9020325
+            | 9000319 | 9000329
+            // ψ(4040), ψ(4160), ψ(4415), Υ(10860), Υ(11020)
+            | 9000443 | 9010443 | 9020443 | 9000553 | 9010553 => {
+                Some(Parity::Odd)
+            }
+            _ => None,
+        }
+    }
+
+    // C-parity of self-conjugate mesons without a quark-model assignment
+    const fn exotic_meson_c_parity(&self) -> Option<Parity> {
+        match self.0 {
+            // a₀(980), π(1800), π₁(1400), π₁(1600), a₁(1640),
+            // a₂(1700), π₂(2100)
+            9000111 | 9010111 | 9000113 | 9010113 | 9020113 | 9000115
+            | 9010115
+            // f₀ and η states
+            | 9000221 | 9010221 | 9020221 | 9030221 | 9040221 | 9050221
+            | 9060221 | 9070221 | 9080221
+            // f₁(1510)
+            | 9000223
+            // f₂ states, f_J(2220), f₄(2300)
+            | 9000225 | 9010225 | 9020225 | 9030225 | 9040225 | 9050225
+            | 9060225 | 9070225 | 9080225 | 9090225 | 9000229
+            | 9010229 => Some(Parity::Even),
+            // ρ(1900), ρ(2150), ρ₃(1990), ρ₃(2250), h₁(1595)
+            9030113 | 9040113 | 9000117 | 9010117 | 9010223
+            // ψ(4040), ψ(4160), ψ(4415), Υ(10860), Υ(11020)
+            | 9000443 | 9010443 | 9020443 | 9000553 | 9010553 => {
+                Some(Parity::Odd)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ParticleID;
+
+// Number of flavors in the usual PDF array layout t̄, ..., g, ..., t
+const NUM_PDF_FLAVORS: usize = 13;
+// Index of the gluon in the PDF array layout
+const GLUON_PDF_INDEX: usize = 6;
+
+impl ParticleID {
+    /// Check if this is a parton with a parton distribution function
+    ///
+    /// This is the case for the gluon and for quarks and anti-quarks
+    /// up to bottom. Top quarks have a slot in the
+    /// [PDF array layout](Self::pdf_index), but are not PDF partons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(gluon.is_pdf_parton());
+    /// assert!(anti_bottom.is_pdf_parton());
+    /// assert!(!top.is_pdf_parton());
+    /// assert!(!photon.is_pdf_parton());
+    /// ```
+    pub const fn is_pdf_parton(&self) -> bool {
+        matches!(self.0, -5..=-1 | 1..=5 | 21)
+    }
+
+    /// Position in an array of the 13 PDF flavors
+    ///
+    /// The array layout is t̄, b̄, c̄, s̄, ū, d̄, g, d, u, s, c, b, t,
+    /// so the gluon has index 6. Unlike the signed convention used by
+    /// LHAPDF, all indices are non-negative. Returns `None` for
+    /// particles without a slot. The outermost slots 0 and 12 belong
+    /// to t̄ and t, even though these are not
+    /// [PDF partons](Self::is_pdf_parton).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(gluon.pdf_index(), Some(6));
+    /// assert_eq!(anti_up.pdf_index(), Some(4));
+    /// assert_eq!(bottom.pdf_index(), Some(11));
+    /// assert_eq!(photon.pdf_index(), None);
+    /// assert_eq!(ParticleID::from_pdf_index(4), Some(anti_up));
+    ///
+    /// // top quarks have a slot, but no PDF
+    /// assert_eq!(top.pdf_index(), Some(12));
+    /// assert_eq!(ParticleID::from_pdf_index(0), Some(anti_top));
+    /// assert!(!top.is_pdf_parton());
+    /// ```
+    pub const fn pdf_index(&self) -> Option<usize> {
+        match self.0 {
+            21 => Some(GLUON_PDF_INDEX),
+            -6..=-1 | 1..=6 => Some((GLUON_PDF_INDEX as i32 + self.0) as usize),
+            _ => None,
+        }
+    }
+
+    /// Particle at the given position in an array of the 13 PDF flavors
+    ///
+    /// See [pdf_index](Self::pdf_index).
+    pub const fn from_pdf_index(index: usize) -> Option<ParticleID> {
+        if index == GLUON_PDF_INDEX {
+            Some(crate::sm_elementary_particles::gluon)
+        } else if index < NUM_PDF_FLAVORS {
+            Some(ParticleID(index as i32 - GLUON_PDF_INDEX as i32))
+        } else {
+            None
+        }
+    }
+}
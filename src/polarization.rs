@@ -0,0 +1,170 @@
+use crate::ParticleID;
+
+/// Polarization state of a particle
+///
+/// Helicities are stored as twice their value, so that half-integer
+/// helicities can be represented exactly.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::Polarization;
+/// let pol = Polarization::Helicity(-1);
+/// assert_eq!(pol.helicity(), Some(-0.5));
+/// assert_eq!(Polarization::Unpolarized.helicity(), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Polarization {
+    /// Unpolarized or unknown polarization
+    Unpolarized,
+    /// Definite helicity, times two
+    Helicity(i32),
+}
+
+impl Polarization {
+    /// Twice the helicity
+    pub const fn helicity_times_two(&self) -> Option<i32> {
+        match self {
+            Polarization::Unpolarized => None,
+            Polarization::Helicity(h) => Some(*h),
+        }
+    }
+
+    /// Helicity
+    pub const fn helicity(&self) -> Option<f64> {
+        match self {
+            Polarization::Unpolarized => None,
+            Polarization::Helicity(h) => Some(*h as f64 / 2.),
+        }
+    }
+}
+
+// SPINUP value for unpolarized particles in Les Houches Event files
+const SPINUP_UNPOLARIZED: f64 = 9.;
+
+impl ParticleID {
+    /// Check if the polarization is allowed for this particle
+    ///
+    /// Massive particles with spin J can have helicities -J, -J+1,
+    /// ..., J. Massless gauge bosons and the graviton can only have
+    /// helicities ±J. Any particle can be unpolarized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::Polarization;
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(Z.allows_polarization(Polarization::Helicity(0)));
+    /// assert!(!photon.allows_polarization(Polarization::Helicity(0)));
+    /// assert!(photon.allows_polarization(Polarization::Helicity(-2)));
+    /// assert!(!electron.allows_polarization(Polarization::Helicity(2)));
+    /// assert!(Higgs.allows_polarization(Polarization::Unpolarized));
+    /// ```
+    pub const fn allows_polarization(
+        &self,
+        polarization: Polarization,
+    ) -> bool {
+        let Polarization::Helicity(h) = polarization else {
+            return true;
+        };
+        let Some(two_j) = self.spin_times_two() else {
+            return false;
+        };
+        let two_j = two_j as i32;
+        if self.is_massless() {
+            h == two_j || h == -two_j
+        } else {
+            -two_j <= h && h <= two_j && (two_j - h) % 2 == 0
+        }
+    }
+
+    /// Polarization corresponding to a Les Houches Event file SPINUP value
+    ///
+    /// A SPINUP value of 9 denotes an unpolarized particle. Otherwise,
+    /// the values -1, 0, 1 denote the sign of the helicity. Returns
+    /// `None` if the value does not correspond to an allowed
+    /// polarization of this particle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::Polarization;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let pol = electron.polarization_from_spinup(-1.);
+    /// assert_eq!(pol, Some(Polarization::Helicity(-1)));
+    /// let pol = W_plus.polarization_from_spinup(1.);
+    /// assert_eq!(pol, Some(Polarization::Helicity(2)));
+    /// assert_eq!(gluon.polarization_from_spinup(0.), None);
+    /// ```
+    pub const fn polarization_from_spinup(
+        &self,
+        spinup: f64,
+    ) -> Option<Polarization> {
+        if spinup == SPINUP_UNPOLARIZED {
+            return Some(Polarization::Unpolarized);
+        }
+        let sign = if spinup == 1. {
+            1
+        } else if spinup == -1. {
+            -1
+        } else if spinup == 0. {
+            0
+        } else {
+            return None;
+        };
+        let Some(two_j) = self.spin_times_two() else {
+            return None;
+        };
+        if two_j == 0 && sign != 0 {
+            return None;
+        }
+        let polarization = Polarization::Helicity(sign * two_j as i32);
+        if self.allows_polarization(polarization) {
+            Some(polarization)
+        } else {
+            None
+        }
+    }
+
+    /// Les Houches Event file SPINUP value for the given polarization
+    ///
+    /// See [polarization_from_spinup](Self::polarization_from_spinup).
+    /// Returns `None` if the polarization is not allowed or cannot be
+    /// represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::Polarization;
+    /// use particle_id::sm_elementary_particles::*;
+    /// let spinup = positron.polarization_to_spinup(Polarization::Helicity(1));
+    /// assert_eq!(spinup, Some(1.));
+    /// let spinup = Z.polarization_to_spinup(Polarization::Unpolarized);
+    /// assert_eq!(spinup, Some(9.));
+    /// ```
+    pub const fn polarization_to_spinup(
+        &self,
+        polarization: Polarization,
+    ) -> Option<f64> {
+        if !self.allows_polarization(polarization) {
+            return None;
+        }
+        match polarization {
+            Polarization::Unpolarized => Some(SPINUP_UNPOLARIZED),
+            Polarization::Helicity(0) => Some(0.),
+            Polarization::Helicity(h) => match self.spin_times_two() {
+                Some(two_j) if h.unsigned_abs() == two_j => {
+                    Some(h.signum() as f64)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    // Massless particles with only two helicity states
+    const fn is_massless(&self) -> bool {
+        use crate::sm_elementary_particles::{g, γ};
+        use crate::special_particles::G;
+        matches!(self.abs(), g | γ | G)
+    }
+}
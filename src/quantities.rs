@@ -0,0 +1,77 @@
+use uom::si::energy::gigaelectronvolt;
+use uom::si::f64::{Energy, Length, Mass, Time};
+use uom::si::length::meter;
+use uom::si::mass::kilogram;
+use uom::si::time::second;
+
+use crate::{LengthUnit, ParticleID};
+
+// 1 GeV/c² in kg
+const GEV_IN_KG: f64 = 1.78266192e-27;
+
+impl ParticleID {
+    /// Mass as a typed quantity
+    ///
+    /// See [mass](Self::mass).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use uom::si::mass::kilogram;
+    /// let mass = electron.mass_quantity().unwrap();
+    /// assert!((mass.get::<kilogram>() - 9.1093837e-31).abs() < 1e-37);
+    /// ```
+    pub fn mass_quantity(&self) -> Option<Mass> {
+        self.mass()
+            .map(|mass| Mass::new::<kilogram>(mass * GEV_IN_KG))
+    }
+
+    /// Total decay width as a typed quantity
+    ///
+    /// See [width](Self::width).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use uom::si::energy::megaelectronvolt;
+    /// let width = Z.width_quantity().unwrap();
+    /// assert!((width.get::<megaelectronvolt>() - 2495.5).abs() < 1e-9);
+    /// ```
+    pub fn width_quantity(&self) -> Option<Energy> {
+        self.width().map(Energy::new::<gigaelectronvolt>)
+    }
+
+    /// Mean lifetime as a typed quantity
+    ///
+    /// See [lifetime](Self::lifetime).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// use uom::si::time::microsecond;
+    /// let lifetime = muon.lifetime_quantity().unwrap();
+    /// assert!((lifetime.get::<microsecond>() - 2.197).abs() < 1e-3);
+    /// ```
+    pub fn lifetime_quantity(&self) -> Option<Time> {
+        self.lifetime().map(Time::new::<second>)
+    }
+
+    /// Mean decay length cτ as a typed quantity
+    ///
+    /// See [ctau_in](Self::ctau_in).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::strange_mesons::*;
+    /// use uom::si::length::centimeter;
+    /// let ctau = K_0_S.ctau_quantity().unwrap();
+    /// assert!((ctau.get::<centimeter>() - 2.684).abs() < 1e-3);
+    /// ```
+    pub fn ctau_quantity(&self) -> Option<Length> {
+        self.ctau_in(LengthUnit::Meter).map(Length::new::<meter>)
+    }
+}
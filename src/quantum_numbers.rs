@@ -0,0 +1,191 @@
+use std::fmt::{self, Display};
+
+use crate::{Parity, ParticleID, Spin};
+
+/// Spin, parity, and isospin quantum numbers of a particle
+///
+/// The [Display] implementation gives the Jᴾᶜ label, omitting the
+/// parities if they are not defined.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+/// use particle_id::light_mesons::*;
+/// use particle_id::light_baryons::*;
+///
+/// let qn = ρ_770_0.quantum_numbers().unwrap();
+/// assert_eq!(qn.to_string(), "1⁻⁻");
+/// assert_eq!(qn.isospin_times_two, Some(2));
+/// assert_eq!(qn.i3_times_two, Some(0));
+/// assert_eq!(π_plus.quantum_numbers().unwrap().to_string(), "0⁻");
+/// assert_eq!(proton.quantum_numbers().unwrap().to_string(), "1/2⁺");
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct QuantumNumbers {
+    /// Spin J
+    pub spin: Spin,
+    /// Parity P
+    pub parity: Option<Parity>,
+    /// Charge-conjugation parity C
+    pub c_parity: Option<Parity>,
+    /// Twice the isospin I
+    pub isospin_times_two: Option<u32>,
+    /// Twice the third isospin component I₃
+    pub i3_times_two: Option<i32>,
+}
+
+impl Display for QuantumNumbers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const fn superscript(parity: Parity) -> char {
+            match parity {
+                Parity::Even => '⁺',
+                Parity::Odd => '⁻',
+            }
+        }
+
+        write!(f, "{}", self.spin)?;
+        if let Some(parity) = self.parity {
+            write!(f, "{}", superscript(parity))?;
+        }
+        if let Some(c_parity) = self.c_parity {
+            write!(f, "{}", superscript(c_parity))?;
+        }
+        Ok(())
+    }
+}
+
+impl ParticleID {
+    /// Spin, parity, and isospin quantum numbers
+    ///
+    /// See [spin](Self::spin), [parity](Self::parity),
+    /// [c_parity](Self::c_parity),
+    /// [isospin_times_two](Self::isospin_times_two), and
+    /// [i3_times_two](Self::i3_times_two). Returns `None` if the spin
+    /// is not known.
+    pub const fn quantum_numbers(&self) -> Option<QuantumNumbers> {
+        let Some(spin) = self.spin() else {
+            return None;
+        };
+        Some(QuantumNumbers {
+            spin,
+            parity: self.parity(),
+            c_parity: self.c_parity(),
+            isospin_times_two: self.isospin_times_two(),
+            i3_times_two: self.i3_times_two(),
+        })
+    }
+
+    /// Twice the strong isospin
+    ///
+    /// For hadrons, the isospin is derived from the quark content
+    /// encoded in the particle code. Excited baryons made of u and d
+    /// quarks are an exception: apart from states with three
+    /// identical quarks, their isospin cannot be determined from the
+    /// code. Returns `None` if the isospin is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(up.isospin_times_two(), Some(1));
+    /// assert_eq!(electron.isospin_times_two(), Some(0));
+    /// assert_eq!(π_0.isospin_times_two(), Some(2));
+    /// assert_eq!(η.isospin_times_two(), Some(0));
+    /// assert_eq!(Δ_minus.isospin_times_two(), Some(3));
+    /// assert_eq!(proton.isospin_times_two(), Some(1));
+    /// // N(1520)
+    /// assert_eq!(ParticleID::new(1214).isospin_times_two(), None);
+    /// assert_eq!(ParticleID::new(2124).isospin_times_two(), None);
+    /// // Δ(1620)
+    /// assert_eq!(ParticleID::new(1112).isospin_times_two(), Some(3));
+    /// assert_eq!(ParticleID::new(2122).isospin_times_two(), None);
+    /// # }
+    /// ```
+    pub const fn isospin_times_two(&self) -> Option<u32> {
+        const fn is_light(q: u32) -> bool {
+            q == 1 || q == 2
+        }
+
+        if self.0 == 130 || self.0 == 310 {
+            return Some(1);
+        }
+        if self.has_meson_digits() {
+            let (q2, q3) = (self.n_q2(), self.n_q3());
+            let two_i = match (is_light(q2), is_light(q3)) {
+                (true, true) if q2 == 2 && q3 == 2 => 0,
+                (true, true) => 2,
+                (true, false) | (false, true) => 1,
+                (false, false) => 0,
+            };
+            return Some(two_i);
+        }
+        if self.has_baryon_digits() {
+            let (q1, q2, q3) = (self.n_q1(), self.n_q2(), self.n_q3());
+            let num_light =
+                is_light(q1) as u32 + is_light(q2) as u32 + is_light(q3) as u32;
+            let two_i = match num_light {
+                0 => 0,
+                1 => 1,
+                // Λ-like baryons have the light quark digits swapped
+                2 if q2 < q3 => 0,
+                2 => 2,
+                // Δ⁺⁺ and Δ⁻ states
+                _ if q1 == q2 && q2 == q3 => 3,
+                // The codes of excited N and Δ states with the same
+                // quark content only differ by conventions, so we
+                // only handle the nucleons and Δ(1232) here
+                _ if self.0.unsigned_abs() < 10_000 && q1 >= q2 && q2 >= q3 => {
+                    match self.n_j() {
+                        2 => 1,
+                        4 => 3,
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+            return Some(two_i);
+        }
+        if !self.is_elementary() {
+            return None;
+        }
+        match self.0.unsigned_abs() {
+            1 | 2 => Some(1),
+            _ => Some(0),
+        }
+    }
+
+    /// Twice the third component of the strong isospin
+    ///
+    /// Returns `None` if the isospin is not known or if this is not
+    /// an isospin eigenstate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::strange_mesons::*;
+    /// assert_eq!(anti_down.i3_times_two(), Some(1));
+    /// assert_eq!(π_plus.anti().i3_times_two(), Some(-2));
+    /// assert_eq!(K_plus.i3_times_two(), Some(1));
+    /// assert_eq!(K_0_S.i3_times_two(), None);
+    /// # }
+    /// ```
+    pub const fn i3_times_two(&self) -> Option<i32> {
+        if self.0 == 130 || self.0 == 310 || self.isospin_times_two().is_none()
+        {
+            return None;
+        }
+        let Some([d, u, ..]) = self.net_quark_numbers() else {
+            return None;
+        };
+        Some(u - d)
+    }
+}
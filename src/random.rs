@@ -0,0 +1,133 @@
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+
+use crate::{ParticleClass, ParticleID};
+
+impl ParticleID {
+    /// Generate a random valid particle code
+    ///
+    /// The class of the particle is chosen randomly according to the
+    /// given weights. The code itself is then drawn from all
+    /// structurally valid codes in that class, not only from the
+    /// particles for which this crate defines constants. Both
+    /// particles and anti-particles are generated. Returns `None` if
+    /// no class has a positive weight or any weight is invalid.
+    ///
+    /// Requires the `rand` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::{ParticleClass, ParticleID};
+    /// use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    /// let weights = [(ParticleClass::Lepton, 1.), (ParticleClass::CharmMeson, 2.)];
+    /// for _ in 0..100 {
+    ///     let p = ParticleID::random_valid(&mut rng, &weights).unwrap();
+    ///     assert!(
+    ///         ParticleClass::Lepton.contains(p)
+    ///             || ParticleClass::CharmMeson.contains(p)
+    ///     );
+    /// }
+    /// let weights = [(ParticleClass::Meson, 1.)];
+    /// for _ in 0..100 {
+    ///     let meson = ParticleID::random_valid(&mut rng, &weights).unwrap();
+    ///     assert!(meson.parity().is_some());
+    /// }
+    /// ```
+    pub fn random_valid<R: Rng + ?Sized>(
+        rng: &mut R,
+        weights: &[(ParticleClass, f64)],
+    ) -> Option<ParticleID> {
+        let dist = WeightedIndex::new(weights.iter().map(|(_, w)| w)).ok()?;
+        let class = weights[dist.sample(rng)].0;
+        loop {
+            let particle = random_candidate(rng, class);
+            if class.contains(particle) {
+                if !particle.is_own_anti_particle() && rng.gen() {
+                    return Some(particle.anti());
+                }
+                return Some(particle);
+            }
+        }
+    }
+}
+
+// Random positive code with the digit structure of the given class
+//
+// The code is not guaranteed to belong to the class.
+fn random_candidate<R: Rng + ?Sized>(
+    rng: &mut R,
+    class: ParticleClass,
+) -> ParticleID {
+    // quark flavors up to bottom, sorted in descending order
+    let mut quarks = [0; 3];
+    for q in &mut quarks {
+        *q = rng.gen_range(1..=5);
+    }
+    quarks.sort_unstable_by(|a, b| b.cmp(a));
+    let [q1, q2, q3] = quarks;
+    let id = match class {
+        ParticleClass::Quark
+        | ParticleClass::Lepton
+        | ParticleClass::ChargedLepton
+        | ParticleClass::Neutrino
+        | ParticleClass::GaugeBoson
+        | ParticleClass::HiggsBoson => rng.gen_range(1..=40),
+        ParticleClass::Diquark => {
+            // two identical quarks require a symmetric spin state
+            let n_j = if q1 == q2 {
+                3
+            } else {
+                *[1, 3].choose(rng).unwrap()
+            };
+            1000 * q1 + 100 * q2 + n_j
+        }
+        ParticleClass::Meson
+        | ParticleClass::LightMeson
+        | ParticleClass::CharmMeson
+        | ParticleClass::BottomMeson
+        | ParticleClass::Charmonium
+        | ParticleClass::Bottomonium => {
+            let n_j = *[1, 3, 5, 7].choose(rng).unwrap();
+            // for J = 0, the orbital angular momentum equals the quark
+            // spin, so only n_L = 0 and n_L = 1 are allowed
+            let n_l = if n_j == 1 {
+                rng.gen_range(0..=1)
+            } else {
+                rng.gen_range(0..=3)
+            };
+            let n_r = rng.gen_range(0..=2);
+            n_r * 100_000 + n_l * 10_000 + 100 * q1 + 10 * q2 + n_j
+        }
+        ParticleClass::Baryon
+        | ParticleClass::CharmBaryon
+        | ParticleClass::BottomBaryon => {
+            // three identical quarks require a symmetric spin state
+            let n_j = if q1 == q3 {
+                4
+            } else {
+                *[2, 4].choose(rng).unwrap()
+            };
+            let excitation =
+                rng.gen_range(0..=2) * 100_000 + rng.gen_range(0..=3) * 10_000;
+            excitation + 1000 * q1 + 100 * q2 + 10 * q3 + n_j
+        }
+        ParticleClass::Pentaquark => {
+            // ±1 n_r n_L n_q1 n_q2 n_q3 n_q4 n_q5 n_J with n_r = n_L = 0
+            let mut id = 100;
+            for _ in 0..5 {
+                id = 10 * id + rng.gen_range(1..=5);
+            }
+            10 * id + [2, 4].choose(rng).unwrap()
+        }
+        ParticleClass::Susy => {
+            if rng.gen() {
+                1_000_000 + rng.gen_range(1..=39)
+            } else {
+                2_000_000 + rng.gen_range(1..=15)
+            }
+        }
+    };
+    ParticleID(id)
+}
@@ -0,0 +1,118 @@
+use crate::ParticleID;
+
+impl ParticleID {
+    /// Label following the conventions of Rivet analyses
+    ///
+    /// This is the name of the corresponding constant in Rivet's `PID`
+    /// namespace, e.g. "PIPLUS" or "NU_MUBAR". For particles without
+    /// such a constant, it is the signed particle code, like in Rivet's
+    /// `toParticleName`. All labels only consist of ASCII letters,
+    /// digits, underscores, and minus signs, so they can be used in
+    /// YODA histogram paths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::ParticleID;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// assert_eq!(electron.rivet_label(), "ELECTRON");
+    /// assert_eq!(nu_mu_bar.rivet_label(), "NU_MUBAR");
+    /// assert_eq!(W_minus.rivet_label(), "WMINUSBOSON");
+    /// assert_eq!(π_plus.anti().rivet_label(), "PIMINUS");
+    /// assert_eq!(ParticleID::new(-9010221).rivet_label(), "-9010221");
+    /// # }
+    /// ```
+    pub fn rivet_label(&self) -> String {
+        match self.rivet_name() {
+            Some(name) => name.to_owned(),
+            None => self.0.to_string(),
+        }
+    }
+
+    // Name of the constant in Rivet's PID namespace
+    const fn rivet_name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            1 => "DQUARK",
+            2 => "UQUARK",
+            3 => "SQUARK",
+            4 => "CQUARK",
+            5 => "BQUARK",
+            6 => "TQUARK",
+            11 => "ELECTRON",
+            -11 => "POSITRON",
+            12 => "NU_E",
+            -12 => "NU_EBAR",
+            13 => "MUON",
+            -13 => "ANTIMUON",
+            14 => "NU_MU",
+            -14 => "NU_MUBAR",
+            15 => "TAU",
+            -15 => "ANTITAU",
+            16 => "NU_TAU",
+            -16 => "NU_TAUBAR",
+            21 => "GLUON",
+            22 => "PHOTON",
+            23 => "ZBOSON",
+            24 => "WPLUSBOSON",
+            -24 => "WMINUSBOSON",
+            25 => "HIGGSBOSON",
+            39 => "GRAVITON",
+            111 => "PI0",
+            211 => "PIPLUS",
+            -211 => "PIMINUS",
+            113 => "RHO0",
+            213 => "RHOPLUS",
+            -213 => "RHOMINUS",
+            130 => "K0L",
+            310 => "K0S",
+            311 => "K0",
+            321 => "KPLUS",
+            -321 => "KMINUS",
+            221 => "ETA",
+            223 => "OMEGA",
+            331 => "ETAPRIME",
+            333 => "PHI",
+            411 => "DPLUS",
+            -411 => "DMINUS",
+            421 => "D0",
+            -421 => "D0BAR",
+            431 => "DSPLUS",
+            -431 => "DSMINUS",
+            443 => "JPSI",
+            100443 => "PSI2S",
+            511 => "B0",
+            -511 => "B0BAR",
+            521 => "BPLUS",
+            -521 => "BMINUS",
+            531 => "B0S",
+            541 => "BCPLUS",
+            -541 => "BCMINUS",
+            553 => "UPSILON1S",
+            100553 => "UPSILON2S",
+            200553 => "UPSILON3S",
+            300553 => "UPSILON4S",
+            2212 => "PROTON",
+            -2212 => "ANTIPROTON",
+            2112 => "NEUTRON",
+            -2112 => "ANTINEUTRON",
+            3122 => "LAMBDA",
+            -3122 => "LAMBDABAR",
+            3222 => "SIGMAPLUS",
+            3212 => "SIGMA0",
+            3112 => "SIGMAMINUS",
+            3322 => "XI0",
+            3312 => "XIMINUS",
+            -3312 => "XIPLUS",
+            3334 => "OMEGAMINUS",
+            -3334 => "OMEGAPLUS",
+            4122 => "LAMBDACPLUS",
+            5122 => "LAMBDAB",
+            1000010020 => "DEUTERON",
+            1000020040 => "ALPHA",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
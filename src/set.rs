@@ -0,0 +1,195 @@
+use crate::ParticleID;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Set of particles
+///
+/// The particles are kept sorted in canonical order, that is by the
+/// absolute value of the particle code, with each particle
+/// immediately before its anti-particle. See
+/// [cmp_by_abs](ParticleID::cmp_by_abs).
+///
+/// # Example
+///
+/// ```
+/// use particle_id::ParticleSet;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let leptons: ParticleSet =
+///     [muon, positron, electron, muon].into_iter().collect();
+/// assert_eq!(leptons.len(), 3);
+/// assert!(leptons.contains(positron));
+/// assert!(!leptons.contains(anti_muon));
+/// assert_eq!(leptons.as_slice(), [electron, positron, muon]);
+/// ```
+///
+/// Since the order is canonical, comparison and hashing do not
+/// depend on the order in which particles were inserted. With the
+/// `serde` feature, sets are serialised as sequences of particles.
+///
+/// ```
+/// use particle_id::ParticleSet;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let bosons: ParticleSet = [photon, gluon].into_iter().collect();
+/// let reordered: ParticleSet = [gluon, photon].into_iter().collect();
+/// assert_eq!(bosons, reordered);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<ParticleID>", into = "Vec<ParticleID>")
+)]
+pub struct ParticleSet {
+    particles: Vec<ParticleID>,
+}
+
+impl ParticleSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of particles in the set
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Check if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Check if the set contains the given particle
+    pub fn contains(&self, particle: ParticleID) -> bool {
+        self.position(particle).is_ok()
+    }
+
+    /// Insert a particle, returning whether it was newly inserted
+    pub fn insert(&mut self, particle: ParticleID) -> bool {
+        match self.position(particle) {
+            Ok(_) => false,
+            Err(pos) => {
+                self.particles.insert(pos, particle);
+                true
+            }
+        }
+    }
+
+    /// Remove a particle, returning whether it was present
+    pub fn remove(&mut self, particle: ParticleID) -> bool {
+        match self.position(particle) {
+            Ok(pos) => {
+                self.particles.remove(pos);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Iterate over all particles in canonical order
+    pub fn iter(&self) -> impl Iterator<Item = ParticleID> + '_ {
+        self.particles.iter().copied()
+    }
+
+    /// All particles in canonical order
+    pub fn as_slice(&self) -> &[ParticleID] {
+        &self.particles
+    }
+
+    /// Set of the charge-conjugated particles
+    ///
+    /// Particles that are their own anti-particles are kept as they
+    /// are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleSet;
+    /// use particle_id::sm_elementary_particles::*;
+    ///
+    /// let set: ParticleSet = [electron, photon].into_iter().collect();
+    /// assert_eq!(set.conjugated().as_slice(), [positron, photon]);
+    /// ```
+    pub fn conjugated(&self) -> Self {
+        self.iter().map(conjugate).collect()
+    }
+
+    /// Smallest set containing all particles and their anti-particles
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::ParticleSet;
+    /// use particle_id::sm_elementary_particles::*;
+    ///
+    /// let set: ParticleSet = [electron, photon].into_iter().collect();
+    /// let closed = set.closed_under_conjugation();
+    /// assert_eq!(closed.as_slice(), [electron, positron, photon]);
+    /// assert_eq!(closed.conjugated(), closed);
+    /// ```
+    pub fn closed_under_conjugation(&self) -> Self {
+        self.iter().flat_map(|p| [p, conjugate(p)]).collect()
+    }
+
+    fn position(&self, particle: ParticleID) -> Result<usize, usize> {
+        self.particles.binary_search_by(|p| p.cmp_by_abs(&particle))
+    }
+}
+
+fn conjugate(particle: ParticleID) -> ParticleID {
+    if particle.is_self_conjugate() {
+        particle.abs()
+    } else {
+        particle.anti()
+    }
+}
+
+impl FromIterator<ParticleID> for ParticleSet {
+    fn from_iter<I: IntoIterator<Item = ParticleID>>(iter: I) -> Self {
+        let mut particles: Vec<_> = iter.into_iter().collect();
+        particles.sort_unstable_by(ParticleID::cmp_by_abs);
+        particles.dedup();
+        Self { particles }
+    }
+}
+
+impl From<Vec<ParticleID>> for ParticleSet {
+    fn from(particles: Vec<ParticleID>) -> Self {
+        particles.into_iter().collect()
+    }
+}
+
+impl From<ParticleSet> for Vec<ParticleID> {
+    fn from(set: ParticleSet) -> Self {
+        set.particles
+    }
+}
+
+impl Extend<ParticleID> for ParticleSet {
+    fn extend<I: IntoIterator<Item = ParticleID>>(&mut self, iter: I) {
+        self.particles.extend(iter);
+        self.particles.sort_unstable_by(ParticleID::cmp_by_abs);
+        self.particles.dedup();
+    }
+}
+
+impl IntoIterator for ParticleSet {
+    type Item = ParticleID;
+    type IntoIter = std::vec::IntoIter<ParticleID>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.particles.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ParticleSet {
+    type Item = ParticleID;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, ParticleID>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.particles.iter().copied()
+    }
+}
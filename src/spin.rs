@@ -0,0 +1,149 @@
+use std::fmt::{self, Display};
+
+use crate::ParticleID;
+
+/// Spin of a particle
+///
+/// The spin is stored exactly, as twice its value.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::Spin;
+/// let spin = Spin::from_times_two(3);
+/// assert_eq!(spin.as_f64(), 1.5);
+/// assert!(spin.is_half_integer());
+/// assert_eq!(spin.multiplicity(), 4);
+/// assert_eq!(spin.to_string(), "3/2");
+/// assert_eq!(Spin::from_times_two(2).to_string(), "1");
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Spin(u32);
+
+impl Spin {
+    /// Spin with the given value times two
+    pub const fn from_times_two(times_two: u32) -> Self {
+        Self(times_two)
+    }
+
+    /// Twice the spin
+    pub const fn times_two(&self) -> u32 {
+        self.0
+    }
+
+    /// Spin as a floating-point number
+    pub const fn as_f64(&self) -> f64 {
+        self.0 as f64 / 2.
+    }
+
+    /// Check if the spin is half-integer, i.e. if it belongs to a fermion
+    pub const fn is_half_integer(&self) -> bool {
+        self.0 % 2 == 1
+    }
+
+    /// Number of spin states 2J+1
+    pub const fn multiplicity(&self) -> u32 {
+        self.0 + 1
+    }
+}
+
+impl Display for Spin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_half_integer() {
+            write!(f, "{}/2", self.0)
+        } else {
+            write!(f, "{}", self.0 / 2)
+        }
+    }
+}
+
+impl ParticleID {
+    /// Spin
+    ///
+    /// See [spin_times_two](Self::spin_times_two).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert_eq!(muon.spin().unwrap().as_f64(), 0.5);
+    /// assert_eq!(Higgs.spin().unwrap().times_two(), 0);
+    /// ```
+    pub const fn spin(&self) -> Option<Spin> {
+        match self.spin_times_two() {
+            Some(two_j) => Some(Spin(two_j)),
+            None => None,
+        }
+    }
+}
+
+impl ParticleID {
+    /// Check if this is a fermion
+    ///
+    /// This is the case for particles with half-integer spin, see
+    /// [spin_times_two](Self::spin_times_two). For nuclei, it is
+    /// determined from the number of nucleons instead. Returns `false`
+    /// if the spin is not known, so a particle can be neither a boson
+    /// nor a fermion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "baryons", feature = "susy"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::ParticleID;
+    /// assert!(electron.is_fermion());
+    /// assert!(proton.anti().is_fermion());
+    /// assert!(g_tilde.is_fermion());
+    /// assert!(!π_plus.is_fermion());
+    /// // ³He
+    /// assert!(ParticleID::new(1000020030).is_fermion());
+    /// # }
+    /// ```
+    pub const fn is_fermion(&self) -> bool {
+        match self.statistics_number() {
+            Some(n) => n % 2 == 1,
+            None => false,
+        }
+    }
+
+    /// Check if this is a boson
+    ///
+    /// See [is_fermion](Self::is_fermion).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "mesons", feature = "susy"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::ParticleID;
+    /// assert!(gluon.is_boson());
+    /// assert!(π_plus.is_boson());
+    /// assert!(e_tilde_L.is_boson());
+    /// assert!(!electron.is_boson());
+    /// // ⁴He
+    /// assert!(ParticleID::new(1000020040).is_boson());
+    /// assert!(!ParticleID::new(0).is_boson());
+    /// # }
+    /// ```
+    pub const fn is_boson(&self) -> bool {
+        match self.statistics_number() {
+            Some(n) => n % 2 == 0,
+            None => false,
+        }
+    }
+
+    // Number that is odd for fermions and even for bosons
+    const fn statistics_number(&self) -> Option<u32> {
+        if self.is_nucleus() {
+            // number of nucleons
+            return Some((self.0.unsigned_abs() / 10) % 1000);
+        }
+        self.spin_times_two()
+    }
+}
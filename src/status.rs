@@ -0,0 +1,212 @@
+//! Status codes of event record entries
+//!
+//! Event records store a status code alongside each particle code.
+//! [StatusCode] covers both the [HepMC](https://doi.org/10.1016/j.cpc.2020.107310)
+//! and the [Les Houches Event
+//! file](https://arxiv.org/abs/hep-ph/0609017) conventions.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::status::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! let event = [
+//!     (electron, StatusCode::from_lhe(-1).unwrap()),
+//!     (positron, StatusCode::from_lhe(-1).unwrap()),
+//!     (Z, StatusCode::from_lhe(2).unwrap()),
+//!     (muon, StatusCode::from_lhe(1).unwrap()),
+//!     (anti_muon, StatusCode::from_lhe(1).unwrap()),
+//! ];
+//! let final_state: Vec<_> = final_state(event).collect();
+//! assert_eq!(final_state, [muon, anti_muon]);
+//! let incoming: Vec<_> = incoming(event).collect();
+//! assert_eq!(incoming, [electron, positron]);
+//! ```
+use crate::ParticleID;
+
+/// Status of an event record entry
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum StatusCode {
+    /// No meaningful status
+    Undefined,
+    /// Final-state particle
+    FinalState,
+    /// Decayed particle or intermediate resonance
+    Decayed,
+    /// Documentation line, not part of the physical event
+    Documentation,
+    /// Incoming beam particle
+    Beam,
+    /// Incoming particle of the hard process
+    Incoming,
+    /// Intermediate space-like propagator
+    SpaceLike,
+    /// Generator-specific HepMC status code
+    Other(i32),
+}
+
+impl StatusCode {
+    /// Status corresponding to a HepMC status code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::StatusCode;
+    /// assert_eq!(StatusCode::from_hepmc(1), StatusCode::FinalState);
+    /// assert_eq!(StatusCode::from_hepmc(4), StatusCode::Beam);
+    /// assert_eq!(StatusCode::from_hepmc(62), StatusCode::Other(62));
+    /// ```
+    pub const fn from_hepmc(code: i32) -> Self {
+        match code {
+            0 => StatusCode::Undefined,
+            1 => StatusCode::FinalState,
+            2 => StatusCode::Decayed,
+            3 => StatusCode::Documentation,
+            4 => StatusCode::Beam,
+            _ => StatusCode::Other(code),
+        }
+    }
+
+    /// HepMC status code
+    ///
+    /// Returns `None` for statuses without a generator-independent
+    /// HepMC equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::StatusCode;
+    /// assert_eq!(StatusCode::Decayed.to_hepmc(), Some(2));
+    /// assert_eq!(StatusCode::Incoming.to_hepmc(), None);
+    /// ```
+    pub const fn to_hepmc(&self) -> Option<i32> {
+        match self {
+            StatusCode::Undefined => Some(0),
+            StatusCode::FinalState => Some(1),
+            StatusCode::Decayed => Some(2),
+            StatusCode::Documentation => Some(3),
+            StatusCode::Beam => Some(4),
+            StatusCode::Incoming | StatusCode::SpaceLike => None,
+            StatusCode::Other(code) => Some(*code),
+        }
+    }
+
+    /// Status corresponding to a Les Houches Event file status code
+    ///
+    /// Returns `None` if the code is not defined in the Les Houches
+    /// Event file standard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::StatusCode;
+    /// assert_eq!(StatusCode::from_lhe(-1), Some(StatusCode::Incoming));
+    /// assert_eq!(StatusCode::from_lhe(2), Some(StatusCode::Decayed));
+    /// assert_eq!(StatusCode::from_lhe(4), None);
+    /// ```
+    pub const fn from_lhe(code: i32) -> Option<Self> {
+        match code {
+            -9 => Some(StatusCode::Beam),
+            -2 => Some(StatusCode::SpaceLike),
+            -1 => Some(StatusCode::Incoming),
+            1 => Some(StatusCode::FinalState),
+            2 => Some(StatusCode::Decayed),
+            3 => Some(StatusCode::Documentation),
+            _ => None,
+        }
+    }
+
+    /// Les Houches Event file status code
+    ///
+    /// Returns `None` for statuses without a Les Houches Event file
+    /// equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::StatusCode;
+    /// assert_eq!(StatusCode::Beam.to_lhe(), Some(-9));
+    /// assert_eq!(StatusCode::Other(62).to_lhe(), None);
+    /// ```
+    pub const fn to_lhe(&self) -> Option<i32> {
+        match self {
+            StatusCode::Beam => Some(-9),
+            StatusCode::SpaceLike => Some(-2),
+            StatusCode::Incoming => Some(-1),
+            StatusCode::FinalState => Some(1),
+            StatusCode::Decayed => Some(2),
+            StatusCode::Documentation => Some(3),
+            StatusCode::Undefined | StatusCode::Other(_) => None,
+        }
+    }
+
+    /// Check if this is the status of a final-state particle
+    pub const fn is_final_state(&self) -> bool {
+        matches!(self, StatusCode::FinalState)
+    }
+
+    /// Check if this is the status of an incoming particle
+    ///
+    /// Both beam particles and incoming particles of the hard
+    /// process are considered incoming.
+    pub const fn is_incoming(&self) -> bool {
+        matches!(self, StatusCode::Beam | StatusCode::Incoming)
+    }
+
+    /// Check if this is the status of an intermediate particle
+    pub const fn is_intermediate(&self) -> bool {
+        matches!(self, StatusCode::Decayed | StatusCode::SpaceLike)
+    }
+}
+
+/// Select the particles with the given status
+pub fn with_status<I>(
+    entries: I,
+    status: StatusCode,
+) -> impl Iterator<Item = ParticleID>
+where
+    I: IntoIterator<Item = (ParticleID, StatusCode)>,
+{
+    select_by(entries, move |s| s == status)
+}
+
+/// Select the final-state particles
+pub fn final_state<I>(entries: I) -> impl Iterator<Item = ParticleID>
+where
+    I: IntoIterator<Item = (ParticleID, StatusCode)>,
+{
+    select_by(entries, |s| s.is_final_state())
+}
+
+/// Select the incoming particles
+///
+/// See [StatusCode::is_incoming].
+pub fn incoming<I>(entries: I) -> impl Iterator<Item = ParticleID>
+where
+    I: IntoIterator<Item = (ParticleID, StatusCode)>,
+{
+    select_by(entries, |s| s.is_incoming())
+}
+
+/// Select the intermediate particles
+pub fn intermediate<I>(entries: I) -> impl Iterator<Item = ParticleID>
+where
+    I: IntoIterator<Item = (ParticleID, StatusCode)>,
+{
+    select_by(entries, |s| s.is_intermediate())
+}
+
+fn select_by<I, F>(
+    entries: I,
+    mut selector: F,
+) -> impl Iterator<Item = ParticleID>
+where
+    I: IntoIterator<Item = (ParticleID, StatusCode)>,
+    F: FnMut(StatusCode) -> bool,
+{
+    entries
+        .into_iter()
+        .filter_map(move |(p, s)| selector(s).then_some(p))
+}
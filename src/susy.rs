@@ -0,0 +1,312 @@
+use crate::ParticleID;
+
+/// Label distinguishing the two sfermions associated with a fermion
+///
+/// For the first two generations, the sfermions are labeled by the
+/// chirality of their fermion partner. For the third generation, they
+/// are labeled by their mass ordering instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SfermionIndex {
+    /// Partner of the left-handed fermion
+    L,
+    /// Partner of the right-handed fermion
+    R,
+    /// Lighter mass eigenstate
+    One,
+    /// Heavier mass eigenstate
+    Two,
+}
+
+impl SfermionIndex {
+    // Leading digit n of the sfermion code
+    const fn prefix(&self) -> i32 {
+        match self {
+            SfermionIndex::L | SfermionIndex::One => 1_000_000,
+            SfermionIndex::R | SfermionIndex::Two => 2_000_000,
+        }
+    }
+}
+
+impl ParticleID {
+    /// Get the superpartner of a Standard Model particle
+    ///
+    /// For fermions, this is the partner of the left-handed fermion
+    /// or, for the third generation, the lighter mass eigenstate. Use
+    /// [sfermion_partner](Self::sfermion_partner) to choose a
+    /// different sfermion. Returns `None` for particles without a
+    /// unique superpartner, like the electroweak gauge and Higgs
+    /// bosons, whose superpartners mix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::susy_anti_particles::*;
+    /// assert_eq!(electron.superpartner(), Some(e_tilde_L));
+    /// assert_eq!(anti_top.superpartner(), Some(t_tilde_bar_1));
+    /// assert_eq!(gluon.superpartner(), Some(g_tilde));
+    /// assert_eq!(photon.superpartner(), None);
+    /// # }
+    /// ```
+    pub const fn superpartner(&self) -> Option<ParticleID> {
+        match self.0.unsigned_abs() {
+            21 | 39 => Some(ParticleID(self.0 + self.0.signum() * 1_000_000)),
+            _ => self.sfermion_partner(SfermionIndex::L),
+        }
+    }
+
+    /// Get the sfermion partner of a Standard Model fermion
+    ///
+    /// The chirality labels `L` and `R` are equivalent to the mass
+    /// labels `One` and `Two`, respectively. Returns `None` if this is
+    /// not a quark or lepton of the first three generations, or for
+    /// the non-existent right-handed sneutrinos.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::SfermionIndex;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// assert_eq!(muon.sfermion_partner(SfermionIndex::R), Some(μ_tilde_R));
+    /// assert_eq!(bottom.sfermion_partner(SfermionIndex::Two), Some(b_tilde_2));
+    /// assert_eq!(nu_e.sfermion_partner(SfermionIndex::R), None);
+    /// # }
+    /// ```
+    pub const fn sfermion_partner(
+        &self,
+        index: SfermionIndex,
+    ) -> Option<ParticleID> {
+        let abs = self.0.unsigned_abs();
+        let is_neutrino = matches!(abs, 12 | 14 | 16);
+        if !matches!(abs, 1..=6 | 11..=16)
+            || (is_neutrino && index.prefix() != 1_000_000)
+        {
+            return None;
+        }
+        Some(ParticleID(self.0 + self.0.signum() * index.prefix()))
+    }
+
+    /// Get the Standard Model partner of a superpartner
+    ///
+    /// Returns `None` if this is not a sfermion, gluino, or
+    /// gravitino. In particular, neutralinos and charginos have no
+    /// unique Standard Model partner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::special_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// assert_eq!(t_tilde_2.sm_partner(), Some(top));
+    /// assert_eq!(e_tilde_R.anti().sm_partner(), Some(positron));
+    /// assert_eq!(G_tilde.sm_partner(), Some(graviton));
+    /// assert_eq!(χ_tilde_0_1.sm_partner(), None);
+    /// # }
+    /// ```
+    pub const fn sm_partner(&self) -> Option<ParticleID> {
+        let abs = self.0.unsigned_abs();
+        let partner = (abs % 1_000_000) as i32 * self.0.signum();
+        match abs {
+            1_000_001..=1_000_006
+            | 1_000_011..=1_000_016
+            | 1_000_021
+            | 1_000_039
+            | 2_000_001..=2_000_006
+            | 2_000_011
+            | 2_000_013
+            | 2_000_015 => Some(ParticleID(partner)),
+            _ => None,
+        }
+    }
+}
+
+impl ParticleID {
+    /// Get the label and fermion partner of a sfermion
+    ///
+    /// Sfermions of the first two generations and sneutrinos are
+    /// labeled by chirality, third-generation charged sfermions by
+    /// mass ordering. Returns `None` if this is not a sfermion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::SfermionIndex;
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// assert_eq!(u_tilde_R.sfermion_index(), Some((SfermionIndex::R, up)));
+    /// assert_eq!(
+    ///     τ_tilde_1.anti().sfermion_index(),
+    ///     Some((SfermionIndex::One, anti_tau))
+    /// );
+    /// assert_eq!(g_tilde.sfermion_index(), None);
+    /// # }
+    /// ```
+    pub const fn sfermion_index(&self) -> Option<(SfermionIndex, ParticleID)> {
+        let abs = self.0.unsigned_abs();
+        if !matches!(abs % 1_000_000, 1..=6 | 11..=16) {
+            return None;
+        }
+        let Some(fermion) = self.sm_partner() else {
+            return None;
+        };
+        let third_generation = matches!(abs % 1_000_000, 5 | 6 | 15);
+        let index = match (abs / 1_000_000, third_generation) {
+            (1, false) => SfermionIndex::L,
+            (2, false) => SfermionIndex::R,
+            (1, true) => SfermionIndex::One,
+            _ => SfermionIndex::Two,
+        };
+        Some((index, fermion))
+    }
+}
+
+impl ParticleID {
+    /// Check if this is a supersymmetric particle
+    ///
+    /// This includes all codes in the 1000000 and 2000000 blocks,
+    /// i.e. the superpartners of the Standard Model particles, of the
+    /// extended Higgs sector, and of the graviton. Note that
+    /// anti-particles are also treated as supersymmetric particles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// assert!(g_tilde.is_susy());
+    /// assert!(t_tilde_1.anti().is_susy());
+    /// assert!(χ_tilde_0_1.is_susy());
+    /// assert!(!top.is_susy());
+    /// # }
+    /// ```
+    pub const fn is_susy(&self) -> bool {
+        matches!(
+            self.0.unsigned_abs(),
+            1_000_001..=1_000_099 | 2_000_001..=2_000_099
+        )
+    }
+
+    /// Check if this is a neutralino
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::susy_particles::*;
+    /// assert!(χ_tilde_0_1.is_neutralino());
+    /// assert!(!χ_tilde_plus_1.is_neutralino());
+    /// # }
+    /// ```
+    pub const fn is_neutralino(&self) -> bool {
+        self.neutralino_index().is_some()
+    }
+
+    /// Check if this is a chargino
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::susy_particles::*;
+    /// assert!(χ_tilde_plus_2.is_chargino());
+    /// assert!(χ_tilde_plus_2.anti().is_chargino());
+    /// assert!(!χ_tilde_0_2.is_chargino());
+    /// # }
+    /// ```
+    pub const fn is_chargino(&self) -> bool {
+        self.chargino_index().is_some()
+    }
+
+    /// Mass-ordered index of a neutralino
+    ///
+    /// The index ranges from 1 to 4 in the MSSM. The fifth neutralino
+    /// of the NMSSM is also included. Returns `None` if this is not a
+    /// neutralino.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::susy_particles::*;
+    /// assert_eq!(χ_tilde_0_3.neutralino_index(), Some(3));
+    /// assert_eq!(g_tilde.neutralino_index(), None);
+    /// # }
+    /// ```
+    pub const fn neutralino_index(&self) -> Option<u32> {
+        let index = match self.0 {
+            1_000_022 => 1,
+            1_000_023 => 2,
+            1_000_025 => 3,
+            1_000_035 => 4,
+            1_000_045 => 5,
+            _ => return None,
+        };
+        Some(index)
+    }
+
+    /// Mass-ordered index of a chargino
+    ///
+    /// Returns `None` if this is not a chargino.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "susy")] {
+    /// use particle_id::susy_particles::*;
+    /// assert_eq!(χ_tilde_plus_1.anti().chargino_index(), Some(1));
+    /// assert_eq!(χ_tilde_plus_2.chargino_index(), Some(2));
+    /// assert_eq!(χ_tilde_0_1.chargino_index(), None);
+    /// # }
+    /// ```
+    pub const fn chargino_index(&self) -> Option<u32> {
+        match self.0.unsigned_abs() {
+            1_000_024 => Some(1),
+            1_000_037 => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl ParticleID {
+    /// R-parity (−1)^(3B+L+2S)
+    ///
+    /// R-parity is +1 for Standard Model particles and -1 for their
+    /// superpartners. Returns `None` if any of the required quantum
+    /// numbers is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "baryons", feature = "susy"))] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::susy_particles::*;
+    /// use particle_id::light_baryons::*;
+    /// assert_eq!(electron.r_parity(), Some(1));
+    /// assert_eq!(proton.r_parity(), Some(1));
+    /// assert_eq!(e_tilde_L.r_parity(), Some(-1));
+    /// assert_eq!(g_tilde.r_parity(), Some(-1));
+    /// # }
+    /// ```
+    pub const fn r_parity(&self) -> Option<i32> {
+        let (Some(b), Some(l), Some(two_s)) = (
+            self.baryon_number_in_thirds(),
+            self.total_lepton_number(),
+            self.spin_times_two(),
+        ) else {
+            return None;
+        };
+        if (b + l + two_s as i32) % 2 == 0 {
+            Some(1)
+        } else {
+            Some(-1)
+        }
+    }
+}
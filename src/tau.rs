@@ -0,0 +1,78 @@
+//! Particles appearing in τ decays
+//!
+//! The sets contain the particles typically found among the
+//! immediate or subsequent decay products of τ leptons in event
+//! records. They are used for example to construct the visible decay
+//! products of τ leptons.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::sm_elementary_particles::*;
+//! use particle_id::light_mesons::*;
+//!
+//! assert!(π_plus.anti().is_tau_decay_product_candidate());
+//! assert!(π_plus.anti().is_visible_tau_decay_product());
+//! assert!(nu_tau.is_tau_decay_product_candidate());
+//! assert!(!nu_tau.is_visible_tau_decay_product());
+//! assert!(!bottom.is_tau_decay_product_candidate());
+//! ```
+use crate::light_mesons::*;
+use crate::sm_elementary_particles::*;
+use crate::strange_mesons::*;
+use crate::ParticleID;
+
+/// Neutrinos and anti-neutrinos from τ decays
+pub const TAU_DECAY_NEUTRINOS: [ParticleID; 6] =
+    [ν_τ, ν_τ_bar, ν_e, ν_e_bar, ν_μ, ν_μ_bar];
+
+/// Charged leptons from leptonic τ decays
+pub const TAU_DECAY_LEPTONS: [ParticleID; 4] = [e, e_bar, μ, μ_bar];
+
+/// Hadrons from hadronic τ decays
+pub const TAU_DECAY_HADRONS: [ParticleID; 11] = [
+    π_plus,
+    π_plus.anti(),
+    π_0,
+    K_plus,
+    K_plus.anti(),
+    K_0,
+    K_0.anti(),
+    K_0_S,
+    K_0_L,
+    η,
+    ω_782,
+];
+
+/// Photons from radiative τ decays and π⁰ decays
+pub const TAU_DECAY_PHOTONS: [ParticleID; 1] = [γ];
+
+const fn contains(set: &[ParticleID], particle: ParticleID) -> bool {
+    let mut i = 0;
+    while i < set.len() {
+        if set[i].0 == particle.0 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+impl ParticleID {
+    /// Check if this particle can appear among the decay products of a τ
+    ///
+    /// See the [tau](crate::tau) module for the included particles.
+    pub const fn is_tau_decay_product_candidate(&self) -> bool {
+        contains(&TAU_DECAY_NEUTRINOS, *self)
+            || self.is_visible_tau_decay_product()
+    }
+
+    /// Check if this particle can be a visible decay product of a τ
+    ///
+    /// This includes all τ decay product candidates except neutrinos.
+    pub const fn is_visible_tau_decay_product(&self) -> bool {
+        contains(&TAU_DECAY_LEPTONS, *self)
+            || contains(&TAU_DECAY_HADRONS, *self)
+            || contains(&TAU_DECAY_PHOTONS, *self)
+    }
+}
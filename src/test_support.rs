@@ -0,0 +1,172 @@
+//! Consistency checks for particle names
+//!
+//! The functions in this module panic if a particle name cannot be
+//! converted back to the original particle. They are meant to be
+//! used in tests, both for the tables built into this crate and for
+//! tables maintained by downstream crates.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::test_support::*;
+//!
+//! assert_all_round_trips(catalogue());
+//! ```
+use std::collections::HashMap;
+
+use crate::index::{from_slot, NUM_KNOWN};
+use crate::{NameStyle, ParticleID};
+
+/// Iterate over all particles and anti-particles known to this crate
+///
+/// Self-conjugate particles only appear once.
+pub fn catalogue() -> impl Iterator<Item = ParticleID> {
+    (0..2 * NUM_KNOWN)
+        .map(from_slot)
+        .filter(|p| !p.is_anti_particle() || !p.abs().is_own_anti_particle())
+}
+
+/// Assert that rendering and parsing particle names is consistent
+///
+/// For each particle, `render` is called to obtain a name. If there
+/// is a name, passing it to `parse` has to return the original
+/// particle.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::ParticleID;
+/// use particle_id::test_support::assert_round_trip;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// let table = [(electron, "e"), (muon, "mu")];
+/// let render = |p: ParticleID| {
+///     table.iter().find(|(q, _)| *q == p).map(|(_, name)| *name)
+/// };
+/// let parse = |text: &str| {
+///     table.iter().find(|(_, name)| *name == text).map(|(p, _)| *p)
+/// };
+/// assert_round_trip([electron, muon, tau], render, parse);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a rendered name is not parsed back to the original
+/// particle.
+pub fn assert_round_trip<I, S, R, P>(particles: I, mut render: R, mut parse: P)
+where
+    I: IntoIterator<Item = ParticleID>,
+    S: AsRef<str>,
+    R: FnMut(ParticleID) -> Option<S>,
+    P: FnMut(&str) -> Option<ParticleID>,
+{
+    for particle in particles {
+        let Some(name) = render(particle) else {
+            continue;
+        };
+        let name = name.as_ref();
+        let parsed = parse(name);
+        assert_eq!(
+            parsed,
+            Some(particle),
+            "{name:?} for {particle:?} is parsed as {parsed:?}"
+        );
+    }
+}
+
+/// Assert that particle codes round-trip through their string
+/// representation
+pub fn assert_code_round_trip<I>(particles: I)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    assert_round_trip(particles, |p| Some(p.id().to_string()), parse)
+}
+
+/// Assert that particle [names](ParticleID::name) are parsed back to
+/// the original particle
+pub fn assert_name_round_trip<I>(particles: I)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    assert_round_trip(particles, |p| p.name(), parse)
+}
+
+/// Assert that particle [symbols](ParticleID::symbol) are parsed back
+/// to the original particle
+pub fn assert_symbol_round_trip<I>(particles: I)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    assert_round_trip(particles, |p| p.symbol(), parse)
+}
+
+/// Assert that [LaTeX symbols](ParticleID::latex_symbol) are unique
+///
+/// LaTeX symbols cannot be parsed, so instead each symbol is looked
+/// up among the symbols of all given particles.
+pub fn assert_latex_round_trip<I>(particles: I)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    let particles: Vec<_> = particles.into_iter().collect();
+    let mut symbols = HashMap::new();
+    for &particle in &particles {
+        if let Some(symbol) = particle.latex_symbol() {
+            symbols.entry(symbol).or_insert(particle);
+        }
+    }
+    assert_round_trip(
+        particles,
+        |p| p.latex_symbol(),
+        |s| symbols.get(s).copied(),
+    )
+}
+
+/// Assert that particle names in the given style are parsed back to
+/// the original particle
+///
+/// For [NameStyle::Latex], see
+/// [assert_latex_round_trip].
+pub fn assert_style_round_trip<I>(particles: I, style: NameStyle)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    if style == NameStyle::Latex {
+        assert_latex_round_trip(particles)
+    } else {
+        assert_round_trip(particles, |p| p.render_name(style), parse)
+    }
+}
+
+/// Assert all round trips for the given particles
+///
+/// This includes codes, names, symbols, and names in all
+/// [NameStyle]s.
+pub fn assert_all_round_trips<I>(particles: I)
+where
+    I: IntoIterator<Item = ParticleID>,
+{
+    let particles: Vec<_> = particles.into_iter().collect();
+    assert_code_round_trip(particles.iter().copied());
+    assert_name_round_trip(particles.iter().copied());
+    assert_symbol_round_trip(particles.iter().copied());
+    for style in STYLES {
+        assert_style_round_trip(particles.iter().copied(), style);
+    }
+}
+
+const STYLES: [NameStyle; 8] = [
+    NameStyle::Pdg,
+    NameStyle::Pythia8,
+    NameStyle::EvtGen,
+    NameStyle::Lhcb,
+    NameStyle::MadGraph,
+    NameStyle::Latex,
+    NameStyle::Utf8,
+    NameStyle::Ascii,
+];
+
+fn parse(name: &str) -> Option<ParticleID> {
+    name.parse().ok()
+}
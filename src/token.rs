@@ -0,0 +1,168 @@
+//! Stable particle tokens for wire formats
+//!
+//! Each known particle is assigned a small `u32` token that does not
+//! depend on the crate version or the enabled features. The token
+//! table is versioned and only ever extended: newer versions append
+//! particles, but never change or remove existing tokens. A token
+//! that is valid in one version therefore has the same meaning in
+//! all later versions.
+//!
+//! The token of a particle is twice its position in the table, plus
+//! one for anti-particles. Self-conjugate particles like the photon
+//! only have even tokens. Peers should agree on a common version
+//! with [negotiate_version] before exchanging tokens.
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::ParticleID;
+//! use particle_id::token::*;
+//! use particle_id::sm_elementary_particles::*;
+//!
+//! // the peer supports versions up to 3
+//! let version = negotiate_version(3).unwrap();
+//! let token = positron.to_token(version).unwrap();
+//! assert_eq!(token, 17);
+//! assert_eq!(ParticleID::from_token(token, version), Some(positron));
+//!
+//! // there is no separate token for the anti-photon
+//! assert_eq!(photon.anti().to_token(version), None);
+//! assert_eq!(ParticleID::from_token(35, version), None);
+//! ```
+use crate::index::KNOWN;
+use crate::ParticleID;
+
+/// Latest version of the token table
+pub const TOKEN_VERSION: u32 = 4;
+
+// Number of table entries in each version
+const TABLE_LEN: [usize; TOKEN_VERSION as usize] = [401, 414, 419, 420];
+
+// Particle codes in the order of token assignment
+//
+// Only append new entries at the end and add the new length to
+// `TABLE_LEN`!
+const TABLE: [i32; 420] = [
+    1, 2, 3, 4, 5, 6, 7, 9, 11, 12, 13, 14, 15, 16, 17, 18, 21, 22, 23, 24, 25,
+    32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 110, 111, 113, 115, 117, 119,
+    130, 211, 213, 215, 217, 219, 221, 223, 225, 227, 229, 310, 311, 313, 315,
+    317, 319, 321, 323, 325, 327, 329, 331, 333, 335, 337, 411, 413, 415, 421,
+    423, 425, 431, 433, 435, 441, 443, 445, 447, 511, 513, 515, 521, 523, 525,
+    531, 533, 535, 541, 543, 545, 551, 553, 555, 557, 990, 1103, 1114, 2101,
+    2103, 2112, 2114, 2203, 2212, 2214, 2224, 3101, 3103, 3112, 3114, 3122,
+    3201, 3203, 3212, 3214, 3222, 3224, 3303, 3312, 3314, 3322, 3324, 3334,
+    4101, 4103, 4112, 4114, 4122, 4132, 4201, 4203, 4212, 4214, 4222, 4224,
+    4232, 4301, 4303, 4312, 4314, 4322, 4324, 4332, 4334, 4403, 4412, 4414,
+    4422, 4424, 4432, 4434, 4444, 5101, 5103, 5112, 5114, 5122, 5132, 5142,
+    5201, 5203, 5212, 5214, 5222, 5224, 5232, 5242, 5301, 5303, 5312, 5314,
+    5322, 5324, 5332, 5334, 5342, 5401, 5403, 5412, 5414, 5422, 5424, 5432,
+    5434, 5442, 5444, 5503, 5512, 5514, 5522, 5524, 5532, 5534, 5542, 5544,
+    5554, 9990, 10111, 10113, 10115, 10211, 10213, 10215, 10221, 10223, 10225,
+    10311, 10313, 10315, 10321, 10323, 10325, 10331, 10333, 10335, 10411,
+    10413, 10421, 10423, 10431, 10433, 10441, 10443, 10511, 10513, 10521,
+    10523, 10531, 10533, 10541, 10543, 10551, 10553, 10555, 20113, 20213,
+    20223, 20313, 20315, 20323, 20325, 20333, 20413, 20423, 20433, 20443,
+    20445, 20513, 20523, 20533, 20543, 20553, 20555, 30113, 30213, 30223,
+    30313, 30323, 30443, 30553, 100111, 100113, 100211, 100213, 100221, 100223,
+    100311, 100313, 100321, 100323, 100331, 100333, 100441, 100443, 100445,
+    100551, 100553, 100555, 100557, 110551, 110553, 110555, 120553, 120555,
+    130553, 200551, 200553, 200555, 210551, 210553, 220553, 300553, 1000001,
+    1000002, 1000003, 1000004, 1000005, 1000006, 1000011, 1000012, 1000013,
+    1000014, 1000015, 1000016, 1000021, 1000022, 1000023, 1000024, 1000025,
+    1000035, 1000037, 1000039, 2000001, 2000002, 2000003, 2000004, 2000005,
+    2000006, 2000011, 2000013, 2000015, 4000001, 4000002, 4000003, 4000004,
+    4000005, 4000006, 4000011, 4000012, 4000013, 4000014, 4000015, 4000016,
+    9000111, 9000113, 9000115, 9000117, 9000211, 9000213, 9000215, 9000217,
+    9000221, 9000223, 9000225, 9000229, 9000311, 9000313, 9000315, 9000319,
+    9000321, 9000323, 9000325, 9000329, 9000443, 9000553, 9010111, 9010113,
+    9010115, 9010117, 9010211, 9010213, 9010215, 9010217, 9010221, 9010223,
+    9010225, 9010229, 9010311, 9010315, 9010317, 9010321, 9010325, 9010327,
+    9010443, 9010553, 9020113, 9020213, 9020221, 9020225, 9020311, 9020315,
+    9020321, 9020325, 9020443, 9030113, 9030213, 9030221, 9030225, 9040113,
+    9040213, 9040221, 9040225, 9050221, 9050225, 9060221, 9060225, 9070221,
+    9070225, 9080221, 9080225, 9090225, 100221132, 100331122, 3000111, 3000113,
+    3000211, 3000213, 3000221, 3000223, 3100021, 3100111, 3100113, 3200111,
+    3200113, 3300113, 3400113, 51, 52, 53, 54, 55, 3000331,
+];
+
+// Every known particle must have a token in the latest version
+const _: () = {
+    assert!(TABLE_LEN[TOKEN_VERSION as usize - 1] == TABLE.len());
+    let mut i = 0;
+    while i < KNOWN.len() {
+        assert!(
+            KNOWN[i].to_token(TOKEN_VERSION).is_some(),
+            "known particle without token"
+        );
+        i += 1;
+    }
+};
+
+/// Check if the given version of the token table is supported
+pub const fn is_supported_version(version: u32) -> bool {
+    version >= 1 && version <= TOKEN_VERSION
+}
+
+/// Agree on a version of the token table
+///
+/// Returns the highest version supported both by this crate and by a
+/// peer that supports all versions up to `peer_version`, or `None`
+/// if there is no such version.
+pub const fn negotiate_version(peer_version: u32) -> Option<u32> {
+    if peer_version == 0 {
+        None
+    } else if peer_version < TOKEN_VERSION {
+        Some(peer_version)
+    } else {
+        Some(TOKEN_VERSION)
+    }
+}
+
+impl ParticleID {
+    /// Token in the given version of the token table
+    ///
+    /// Returns `None` if the version is not supported or the particle
+    /// is not part of this version. Negative codes of self-conjugate
+    /// particles are also rejected.
+    pub const fn to_token(&self, version: u32) -> Option<u32> {
+        if !is_supported_version(version) {
+            return None;
+        }
+        if self.is_anti_particle() && self.abs().is_own_anti_particle() {
+            return None;
+        }
+        let id = self.0.unsigned_abs() as i32;
+        let mut idx = 0;
+        while idx < TABLE_LEN[version as usize - 1] {
+            if TABLE[idx] == id {
+                return Some(2 * idx as u32 + self.is_anti_particle() as u32);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    /// Particle corresponding to a token
+    ///
+    /// Returns `None` if the version is not supported or the token is
+    /// not part of this version. Odd tokens of self-conjugate particles
+    /// are invalid.
+    pub const fn from_token(token: u32, version: u32) -> Option<ParticleID> {
+        if !is_supported_version(version) {
+            return None;
+        }
+        let idx = (token / 2) as usize;
+        if idx >= TABLE_LEN[version as usize - 1] {
+            return None;
+        }
+        let particle = ParticleID(TABLE[idx]);
+        if token % 2 == 1 {
+            if particle.is_own_anti_particle() {
+                return None;
+            }
+            Some(particle.anti())
+        } else {
+            Some(particle)
+        }
+    }
+}
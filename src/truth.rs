@@ -0,0 +1,101 @@
+use crate::ParticleID;
+
+// Weakly decaying hadrons with a mean decay length above about 10 µm
+#[rustfmt::skip]
+const LONG_LIVED_HADRONS: [u32; 27] = [
+    // light and strange
+    211, 321, 130, 310, 2212, 2112, 3122, 3222, 3112, 3312, 3322, 3334,
+    // charm
+    411, 421, 431, 4122, 4132, 4232, 4332,
+    // bottom
+    511, 521, 531, 541, 5122, 5132, 5232, 5332,
+];
+
+impl ParticleID {
+    /// Check if this particle decays promptly
+    ///
+    /// Promptly decaying particles are heavy elementary particles
+    /// like the top quark, the W, Z, and Higgs bosons, as well as
+    /// hadronic resonances decaying via the strong or electromagnetic
+    /// interaction. Particles with a mean decay length above about 10
+    /// µm, like the muon, the tau, and weakly decaying hadrons, are
+    /// not considered prompt, and neither are stable particles,
+    /// partons, or nuclei.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "mesons")] {
+    /// use particle_id::sm_elementary_particles::*;
+    /// use particle_id::light_mesons::*;
+    /// use particle_id::bottom_mesons::*;
+    /// assert!(Z.is_promptly_decaying());
+    /// assert!(π_0.is_promptly_decaying());
+    /// assert!(ρ_770_0.is_promptly_decaying());
+    /// assert!(!π_plus.is_promptly_decaying());
+    /// assert!(!B_0.is_promptly_decaying());
+    /// assert!(!tau.is_promptly_decaying());
+    /// assert!(!electron.is_promptly_decaying());
+    /// # }
+    /// ```
+    pub const fn is_promptly_decaying(&self) -> bool {
+        let abs = self.0.unsigned_abs();
+        if matches!(abs, 6 | 8 | 23..=25 | 32..=37) {
+            return true;
+        }
+        if self.has_diquark_digits()
+            || self.has_nucleus_digits()
+            || !self.is_composite()
+        {
+            return false;
+        }
+        let mut i = 0;
+        while i < LONG_LIVED_HADRONS.len() {
+            if LONG_LIVED_HADRONS[i] == abs {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Check if this particle hadronizes
+    ///
+    /// This is the case for the gluon, for diquarks, and for all
+    /// quarks except the top quark, which decays before it can
+    /// hadronize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(gluon.hadronizes());
+    /// assert!(anti_bottom.hadronizes());
+    /// assert!(!top.hadronizes());
+    /// assert!(!electron.hadronizes());
+    /// ```
+    pub const fn hadronizes(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 1..=5 | 7 | 21)
+            || self.has_diquark_digits()
+    }
+
+    /// Check if this particle is typically an intermediate parton
+    /// shower state
+    ///
+    /// Shower intermediates are quarks, gluons, diquarks, and
+    /// photons. The check is purely based on the species and ignores
+    /// the status in the event record.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use particle_id::sm_elementary_particles::*;
+    /// assert!(gluon.is_shower_intermediate());
+    /// assert!(photon.is_shower_intermediate());
+    /// assert!(!electron.is_shower_intermediate());
+    /// ```
+    pub const fn is_shower_intermediate(&self) -> bool {
+        matches!(self.0.unsigned_abs(), 1..=8 | 21 | 22)
+            || self.has_diquark_digits()
+    }
+}
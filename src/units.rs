@@ -0,0 +1,57 @@
+/// Units for energies, masses, and widths
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub enum EnergyUnit {
+    /// Electronvolt
+    EV,
+    /// Kiloelectronvolt
+    KeV,
+    /// Megaelectronvolt
+    MeV,
+    /// Gigaelectronvolt
+    #[default]
+    GeV,
+    /// Teraelectronvolt
+    TeV,
+}
+
+impl EnergyUnit {
+    /// Value of this unit in GeV
+    pub const fn in_gev(&self) -> f64 {
+        match self {
+            EnergyUnit::EV => 1e-9,
+            EnergyUnit::KeV => 1e-6,
+            EnergyUnit::MeV => 1e-3,
+            EnergyUnit::GeV => 1.,
+            EnergyUnit::TeV => 1e3,
+        }
+    }
+}
+
+/// Units for lengths
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub enum LengthUnit {
+    /// Femtometer
+    Femtometer,
+    /// Micrometer
+    Micrometer,
+    /// Millimeter
+    #[default]
+    Millimeter,
+    /// Centimeter
+    Centimeter,
+    /// Meter
+    Meter,
+}
+
+impl LengthUnit {
+    /// Value of this unit in meters
+    pub const fn in_meters(&self) -> f64 {
+        match self {
+            LengthUnit::Femtometer => 1e-15,
+            LengthUnit::Micrometer => 1e-6,
+            LengthUnit::Millimeter => 1e-3,
+            LengthUnit::Centimeter => 1e-2,
+            LengthUnit::Meter => 1.,
+        }
+    }
+}
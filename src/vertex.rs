@@ -0,0 +1,286 @@
+//! Conservation laws in interactions
+//!
+//! # Example
+//!
+//! ```
+//! use particle_id::vertex::*;
+//! use particle_id::sm_elementary_particles::*;
+//! use particle_id::Error;
+//!
+//! let vertex = Vertex::new([u, d_bar], [W_plus]);
+//! assert!(vertex.check().is_ok());
+//!
+//! let vertex = Vertex::new([u, d], [W_plus]);
+//! assert_eq!(
+//!     vertex.check(),
+//!     Err(Error::NotConserved(ConservationLaw::Charge))
+//! );
+//!
+//! // μ⁻ → e⁻ γ
+//! assert!(!violates_lepton_number(&[muon], &[electron, photon]));
+//! assert!(violates_lepton_flavor(&[muon], &[electron, photon]));
+//! ```
+use std::fmt::{self, Display};
+
+use crate::{Error, ParticleID};
+
+/// Conservation laws checked at a vertex
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum ConservationLaw {
+    /// Conservation of electric charge
+    Charge,
+    /// Conservation of color triality
+    ColorTriality,
+    /// Conservation of baryon number
+    BaryonNumber,
+    /// Conservation of lepton number
+    LeptonNumber,
+}
+
+impl Display for ConservationLaw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let law = match self {
+            ConservationLaw::Charge => "charge",
+            ConservationLaw::ColorTriality => "color triality",
+            ConservationLaw::BaryonNumber => "baryon number",
+            ConservationLaw::LeptonNumber => "lepton number",
+        };
+        write!(f, "{law}")
+    }
+}
+
+/// Interaction vertex with incoming and outgoing particles
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Vertex {
+    /// Incoming particles
+    pub incoming: Vec<ParticleID>,
+    /// Outgoing particles
+    pub outgoing: Vec<ParticleID>,
+}
+
+impl Vertex {
+    /// Construct a vertex from incoming and outgoing particles
+    pub fn new<I, O>(incoming: I, outgoing: O) -> Self
+    where
+        I: IntoIterator<Item = ParticleID>,
+        O: IntoIterator<Item = ParticleID>,
+    {
+        Self {
+            incoming: incoming.into_iter().collect(),
+            outgoing: outgoing.into_iter().collect(),
+        }
+    }
+
+    /// Check all conservation laws
+    ///
+    /// The laws are checked in the order charge, color triality,
+    /// baryon number, lepton number. Returns an error for the first
+    /// violated law or the first particle for which the required
+    /// quantum numbers are not known.
+    pub fn check(&self) -> Result<(), Error> {
+        self.check_law(ConservationLaw::Charge, |p| p.charge_in_thirds())?;
+        self.check_law(ConservationLaw::ColorTriality, |p| {
+            p.triality().map(|t| t as i32)
+        })?;
+        self.check_law(ConservationLaw::BaryonNumber, |p| {
+            p.baryon_number_in_thirds()
+        })?;
+        self.check_law(ConservationLaw::LeptonNumber, |p| {
+            p.total_lepton_number()
+        })
+    }
+
+    fn check_law(
+        &self,
+        law: ConservationLaw,
+        quantum_number: impl Fn(ParticleID) -> Option<i32>,
+    ) -> Result<(), Error> {
+        let sum = |particles: &[ParticleID]| {
+            particles.iter().try_fold(0, |sum, p| {
+                quantum_number(*p)
+                    .map(|n| sum + n)
+                    .ok_or(Error::UnknownParticle(*p))
+            })
+        };
+        let incoming = sum(&self.incoming)?;
+        let outgoing = sum(&self.outgoing)?;
+        let conserved = if law == ConservationLaw::ColorTriality {
+            (incoming - outgoing) % 3 == 0
+        } else {
+            incoming == outgoing
+        };
+        if conserved {
+            Ok(())
+        } else {
+            Err(Error::NotConserved(law))
+        }
+    }
+}
+
+/// Check if a process violates total lepton number conservation
+///
+/// Particles with invalid codes are ignored.
+pub fn violates_lepton_number(
+    initial: &[ParticleID],
+    final_state: &[ParticleID],
+) -> bool {
+    let sum = |particles: &[ParticleID]| -> i32 {
+        particles
+            .iter()
+            .filter_map(|p| p.total_lepton_number())
+            .sum()
+    };
+    sum(initial) != sum(final_state)
+}
+
+/// Check if a process violates conservation of the individual lepton
+/// flavor numbers
+///
+/// This also includes processes that violate total lepton number
+/// conservation. Particles with invalid codes are ignored.
+pub fn violates_lepton_flavor(
+    initial: &[ParticleID],
+    final_state: &[ParticleID],
+) -> bool {
+    let sum = |particles: &[ParticleID]| {
+        particles.iter().fold([0; 4], |mut sum, p| {
+            for (s, n) in sum.iter_mut().zip(p.lepton_flavor_numbers()) {
+                *s += n;
+            }
+            sum
+        })
+    };
+    violates_lepton_number(initial, final_state)
+        || sum(initial) != sum(final_state)
+}
+
+/// Check if a process violates baryon number conservation
+///
+/// Particles with invalid codes are ignored.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "mesons", feature = "baryons"))] {
+/// use particle_id::vertex::violates_baryon_number;
+/// use particle_id::sm_elementary_particles::*;
+/// use particle_id::light_baryons::*;
+/// use particle_id::light_mesons::*;
+///
+/// // p → e⁺ π⁰
+/// assert!(violates_baryon_number(&[proton], &[positron, π_0]));
+/// assert!(!violates_baryon_number(&[neutron], &[proton, electron, nu_e_bar]));
+/// # }
+/// ```
+pub fn violates_baryon_number(
+    initial: &[ParticleID],
+    final_state: &[ParticleID],
+) -> bool {
+    let sum = |particles: &[ParticleID]| -> i32 {
+        particles
+            .iter()
+            .filter_map(|p| p.baryon_number_in_thirds())
+            .sum()
+    };
+    sum(initial) != sum(final_state)
+}
+
+/// Check if the given particles can form a color singlet
+///
+/// This requires the color trialities to add up to zero modulo
+/// three. Furthermore, a single colored particle can never form a
+/// singlet on its own. Returns `false` if the color of any particle
+/// is unknown.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::vertex::is_color_singlet;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// assert!(is_color_singlet(&[u, u, d]));
+/// assert!(is_color_singlet(&[u, d_bar, g]));
+/// assert!(is_color_singlet(&[electron, positron]));
+/// assert!(!is_color_singlet(&[u, d]));
+/// assert!(!is_color_singlet(&[g, photon]));
+/// ```
+pub fn is_color_singlet(particles: &[ParticleID]) -> bool {
+    let mut triality = 0;
+    let mut num_colored = 0;
+    for particle in particles {
+        let Some(t) = particle.triality() else {
+            return false;
+        };
+        triality += t;
+        if particle.color_multiplicity() > 1 {
+            num_colored += 1;
+        }
+    }
+    triality % 3 == 0 && num_colored != 1
+}
+
+/// Check if the given particles are a valid decay of an electroweak
+/// boson
+///
+/// The products have to be a fermion–anti-fermion pair conserving
+/// charge and lepton flavor. For a W boson this means an up-type and
+/// a down-type quark or a charged lepton with the neutrino of the
+/// same flavor, and for a Z boson a particle together with its
+/// anti-particle. The Higgs boson can decay into a pair of massive
+/// fermions or into W⁺W⁻, ZZ, Zγ, γγ, or gg. The order of the
+/// products does not matter. Returns `false` for all other bosons.
+///
+/// # Example
+///
+/// ```
+/// use particle_id::vertex::is_valid_boson_decay;
+/// use particle_id::sm_elementary_particles::*;
+///
+/// assert!(is_valid_boson_decay(W_plus, &[u, d_bar]));
+/// assert!(is_valid_boson_decay(W_plus, &[nu_e, positron]));
+/// assert!(!is_valid_boson_decay(W_plus, &[u, d]));
+/// assert!(!is_valid_boson_decay(W_plus, &[positron, nu_mu]));
+/// assert!(is_valid_boson_decay(Z, &[b_bar, bottom]));
+/// assert!(!is_valid_boson_decay(Z, &[c, u_bar]));
+/// assert!(is_valid_boson_decay(Higgs, &[photon, photon]));
+/// assert!(is_valid_boson_decay(Higgs, &[W_minus, W_plus]));
+/// assert!(!is_valid_boson_decay(Higgs, &[nu_e, nu_e_bar]));
+/// assert!(!is_valid_boson_decay(photon, &[electron, positron]));
+/// ```
+pub fn is_valid_boson_decay(
+    boson: ParticleID,
+    products: &[ParticleID],
+) -> bool {
+    let &[first, second] = products else {
+        return false;
+    };
+    let (first, second) = if first.id() <= second.id() {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    match boson.id() {
+        23 => is_fermion_pair(first, second) && first.is_conjugate_of(second),
+        24 | -24 => {
+            let charge =
+                first.charge_in_thirds().zip(second.charge_in_thirds());
+            is_fermion_pair(first, second)
+                && charge.map(|(c1, c2)| c1 + c2) == boson.charge_in_thirds()
+                && !violates_lepton_flavor(&[], products)
+        }
+        25 => match (first.id(), second.id()) {
+            (-24, 24) | (23, 23) | (22, 23) | (22, 22) | (21, 21) => true,
+            _ => {
+                is_fermion_pair(first, second)
+                    && first.is_conjugate_of(second)
+                    && !second.is_neutrino()
+            }
+        },
+        _ => false,
+    }
+}
+
+fn is_fermion_pair(anti_fermion: ParticleID, fermion: ParticleID) -> bool {
+    (anti_fermion.is_anti_quark() && fermion.is_quark())
+        || (anti_fermion.is_anti_lepton() && fermion.is_lepton())
+}